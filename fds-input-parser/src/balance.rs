@@ -0,0 +1,229 @@
+//! Assigning meshes to MPI processes so that each process has a similar number
+//! of cells to update.
+use crate::cost::MpiProcessError;
+use crate::decode::*;
+use crate::validate::{abutting_axis, GRID_TOLERANCE};
+
+/// A proposed assignment of meshes to MPI processes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LoadBalance {
+    /// The MPI process for each mesh, indexed as [`FDSFile::meshes`].
+    pub mpi_processes: Vec<i64>,
+    /// The indices of the meshes in the order they must be listed for the
+    /// assignment, as FDS requires MPI_PROCESS to be non-decreasing through
+    /// the input file.
+    pub order: Vec<usize>,
+    /// The number of cells on each process.
+    pub loads: Vec<u64>,
+}
+
+/// A reason no assignment of meshes to MPI processes can be proposed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoadBalanceError {
+    /// The meshes must be assigned to at least one process.
+    NoProcesses,
+}
+
+impl std::fmt::Display for LoadBalanceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoProcesses => write!(f, "at least one MPI process is required"),
+        }
+    }
+}
+
+impl std::error::Error for LoadBalanceError {}
+
+impl LoadBalance {
+    /// The ratio of the largest load to the mean load. A perfectly balanced
+    /// assignment has an imbalance of 1.0, and the run time of a simulation is
+    /// roughly proportional to it.
+    pub fn imbalance(&self) -> f64 {
+        imbalance(&self.loads)
+    }
+}
+
+fn imbalance(loads: &[u64]) -> f64 {
+    let max = loads.iter().copied().max().unwrap_or(0);
+    let total: u64 = loads.iter().sum();
+    if total == 0 {
+        1.0
+    } else {
+        (max as f64) * (loads.len() as f64) / (total as f64)
+    }
+}
+
+/// Assign the meshes to at most `n` groups, none of which sum to more than
+/// `capacity`, by growing each group from a seed mesh over the face-adjacency
+/// graph. A group takes the neighbouring mesh with the most faces shared with
+/// it, and only jumps to an unconnected mesh once it has no neighbours left.
+/// Every group is given at least one mesh where possible. Returns the meshes
+/// of each group in the order they were added, or [`None`] if more than `n`
+/// groups are needed.
+fn grow_partitions(
+    loads: &[u64],
+    adjacency: &[Vec<usize>],
+    n: usize,
+    capacity: u64,
+) -> Option<Vec<Vec<usize>>> {
+    let mut group_of: Vec<Option<usize>> = vec![None; loads.len()];
+    let mut unassigned = loads.len();
+    let mut groups = Vec::with_capacity(n);
+    for group in 0..n {
+        if unassigned == 0 {
+            break;
+        }
+        let mut members: Vec<usize> = Vec::new();
+        let mut sum = 0;
+        loop {
+            let remaining_groups = n - group - 1;
+            if !members.is_empty() && unassigned <= remaining_groups {
+                break;
+            }
+            let links = |mesh: usize| {
+                adjacency[mesh]
+                    .iter()
+                    .filter(|&&other| group_of[other] == Some(group))
+                    .count()
+            };
+            let free_neighbours = |mesh: usize| {
+                adjacency[mesh]
+                    .iter()
+                    .filter(|&&other| group_of[other].is_none())
+                    .count()
+            };
+            let candidates = (0..loads.len()).filter(|&mesh| group_of[mesh].is_none());
+            let next = if members.is_empty() {
+                // Seed from the edge of what is left, so that the group does
+                // not cut the remaining meshes in two.
+                candidates.min_by_key(|&mesh| free_neighbours(mesh))
+            } else {
+                let frontier: Vec<usize> = candidates.filter(|&mesh| links(mesh) > 0).collect();
+                if frontier.is_empty() {
+                    (0..loads.len())
+                        .filter(|&mesh| group_of[mesh].is_none())
+                        .filter(|&mesh| sum + loads[mesh] <= capacity)
+                        .min_by_key(|&mesh| free_neighbours(mesh))
+                } else {
+                    frontier
+                        .into_iter()
+                        .filter(|&mesh| sum + loads[mesh] <= capacity)
+                        .max_by_key(|&mesh| (links(mesh), std::cmp::Reverse(mesh)))
+                }
+            };
+            let mesh = match next {
+                Some(mesh) => mesh,
+                None => break,
+            };
+            if loads[mesh] > capacity {
+                return None;
+            }
+            group_of[mesh] = Some(group);
+            unassigned -= 1;
+            sum += loads[mesh];
+            members.push(mesh);
+        }
+        groups.push(members);
+    }
+    if unassigned > 0 {
+        None
+    } else {
+        Some(groups)
+    }
+}
+
+impl FDSFile {
+    /// The load on each MPI process with the current MPI_PROCESS values.
+    pub fn mpi_loads(&self) -> Result<Vec<u64>, MpiProcessError> {
+        let processes = self.mpi_processes()?;
+        let n = processes.iter().map(|&p| p + 1).max().unwrap_or(0) as usize;
+        let mut loads = vec![0; n];
+        for (mesh, process) in self.meshes.iter().zip(processes) {
+            loads[process as usize] += mesh.n_cells();
+        }
+        Ok(loads)
+    }
+
+    /// Propose an assignment of the meshes to `n_processes` MPI processes
+    /// which minimises the largest number of cells on a process. To keep
+    /// neighbouring meshes on the same process (reducing communication), each
+    /// process is grown over the meshes which share a face with those it
+    /// already has.
+    pub fn balance_mpi(&self, n_processes: usize) -> Result<LoadBalance, LoadBalanceError> {
+        if n_processes == 0 {
+            return Err(LoadBalanceError::NoProcesses);
+        }
+        let loads: Vec<u64> = self.meshes.iter().map(|mesh| mesh.n_cells()).collect();
+        let mut adjacency = vec![Vec::new(); self.meshes.len()];
+        for (i, a) in self.meshes.iter().enumerate() {
+            for (j, b) in self.meshes.iter().enumerate().skip(i + 1) {
+                let tolerance =
+                    GRID_TOLERANCE * a.resolution().max_side().min(b.resolution().max_side());
+                if abutting_axis(&a.xb, &b.xb, tolerance).is_some() {
+                    adjacency[i].push(j);
+                    adjacency[j].push(i);
+                }
+            }
+        }
+
+        // Binary search for the smallest capacity which can be met. Growing
+        // the partitions always succeeds when one process can take every mesh.
+        let mut lower = loads.iter().copied().max().unwrap_or(0);
+        let mut upper = loads.iter().sum::<u64>().max(lower);
+        let mut groups = grow_partitions(&loads, &adjacency, n_processes, upper)
+            .expect("the first process can take every mesh");
+        while lower < upper {
+            let capacity = lower + (upper - lower) / 2;
+            match grow_partitions(&loads, &adjacency, n_processes, capacity) {
+                Some(found) => {
+                    groups = found;
+                    upper = capacity;
+                }
+                None => lower = capacity + 1,
+            }
+        }
+
+        let mut mpi_processes = vec![0; self.meshes.len()];
+        let mut process_loads = vec![0; n_processes];
+        let mut order = Vec::with_capacity(self.meshes.len());
+        for (process, members) in groups.iter().enumerate() {
+            for &mesh in members {
+                mpi_processes[mesh] = process as i64;
+                process_loads[process] += loads[mesh];
+                order.push(mesh);
+            }
+        }
+        Ok(LoadBalance {
+            mpi_processes,
+            order,
+            loads: process_loads,
+        })
+    }
+
+    /// Apply a proposed assignment, setting MPI_PROCESS on each mesh and
+    /// reordering the meshes as FDS requires. MESH_NUMBER on TRNX, TRNY, TRNZ,
+    /// and SLCF is updated to follow the meshes.
+    pub fn apply_load_balance(&mut self, balance: &LoadBalance) {
+        let mut new_numbers = vec![0; self.meshes.len()];
+        for (new_index, &old_index) in balance.order.iter().enumerate() {
+            new_numbers[old_index] = new_index as i64 + 1;
+        }
+        self.renumber_meshes(|mesh_number| {
+            new_numbers
+                .get((mesh_number - 1) as usize)
+                .copied()
+                .unwrap_or(mesh_number)
+        });
+        let mut meshes: Vec<Mesh> = balance
+            .order
+            .iter()
+            .map(|&i| self.meshes[i].clone())
+            .collect();
+        for (mesh, &old_index) in meshes.iter_mut().zip(&balance.order) {
+            mesh.mpi_process = Some(balance.mpi_processes[old_index]);
+        }
+        self.meshes = meshes;
+    }
+}
@@ -0,0 +1,1851 @@
+//! A catalog of the parameters of each FDS namelist group, as data. The
+//! decoder takes its defaults from here, and the same tables can be used to
+//! check, format, or complete input files.
+//!
+//! The catalog covers FDS 6: a parameter is listed if any FDS 6 release
+//! accepts it, along with the releases which do (so MISC DNS, replaced by
+//! SIMULATION_MODE in FDS 6.1, is accepted only by FDS 6.0). Parameters
+//! without a default have none in FDS, or one which depends on other
+//! parameters. Units and valid ranges are given where they are fixed. As the
+//! catalog is static, its types can be serialized (with the `serde` feature)
+//! but not deserialized.
+
+/// The type of the values a parameter takes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Kind {
+    Character,
+    Integer,
+    Real,
+    Logical,
+}
+
+/// The number of values a parameter takes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Shape {
+    Scalar,
+    /// A fixed number of values, such as the 6 of an XB.
+    Array(usize),
+    /// Any number of values, indexed from 1.
+    Vector,
+    /// A two-dimensional array, indexed from (1,1).
+    Matrix,
+}
+
+/// The default value of a parameter.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Value {
+    Character(&'static str),
+    Integer(i64),
+    Real(f64),
+    Logical(bool),
+    Integers(&'static [i64]),
+    Reals(&'static [f64]),
+}
+
+/// A release of FDS.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FdsVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl FdsVersion {
+    pub const fn new(major: u32, minor: u32) -> Self {
+        FdsVersion { major, minor }
+    }
+}
+
+/// The releases of FDS which accept a parameter, from `first` up to and
+/// including `last`, or every later release if `last` is [`None`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Versions {
+    pub first: FdsVersion,
+    pub last: Option<FdsVersion>,
+}
+
+impl Versions {
+    pub fn accepts(&self, version: FdsVersion) -> bool {
+        self.first <= version
+            && match self.last {
+                Some(last) => version <= last,
+                None => true,
+            }
+    }
+}
+
+/// A parameter of a namelist group.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Parameter {
+    pub name: &'static str,
+    pub kind: Kind,
+    pub shape: Shape,
+    pub default: Option<Value>,
+    pub units: Option<&'static str>,
+    /// The smallest valid value, inclusive.
+    pub min: Option<f64>,
+    /// The largest valid value, inclusive.
+    pub max: Option<f64>,
+    pub versions: Versions,
+}
+
+impl Parameter {
+    const fn new(name: &'static str, kind: Kind) -> Self {
+        Parameter {
+            name,
+            kind,
+            shape: Shape::Scalar,
+            default: None,
+            units: None,
+            min: None,
+            max: None,
+            versions: Versions {
+                first: FdsVersion::new(6, 0),
+                last: None,
+            },
+        }
+    }
+
+    const fn array(self, n: usize) -> Self {
+        Parameter {
+            shape: Shape::Array(n),
+            ..self
+        }
+    }
+
+    const fn vector(self) -> Self {
+        Parameter {
+            shape: Shape::Vector,
+            ..self
+        }
+    }
+
+    const fn matrix(self) -> Self {
+        Parameter {
+            shape: Shape::Matrix,
+            ..self
+        }
+    }
+
+    const fn default(self, value: Value) -> Self {
+        Parameter {
+            default: Some(value),
+            ..self
+        }
+    }
+
+    const fn units(self, units: &'static str) -> Self {
+        Parameter {
+            units: Some(units),
+            ..self
+        }
+    }
+
+    const fn min(self, min: f64) -> Self {
+        Parameter {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    const fn max(self, max: f64) -> Self {
+        Parameter {
+            max: Some(max),
+            ..self
+        }
+    }
+
+    /// The parameter was added in FDS `major`.`minor`.
+    const fn since(self, major: u32, minor: u32) -> Self {
+        Parameter {
+            versions: Versions {
+                first: FdsVersion::new(major, minor),
+                ..self.versions
+            },
+            ..self
+        }
+    }
+
+    /// The parameter was dropped after FDS `major`.`minor`.
+    const fn until(self, major: u32, minor: u32) -> Self {
+        Parameter {
+            versions: Versions {
+                last: Some(FdsVersion::new(major, minor)),
+                ..self.versions
+            },
+            ..self
+        }
+    }
+}
+
+const fn character(name: &'static str) -> Parameter {
+    Parameter::new(name, Kind::Character)
+}
+
+const fn integer(name: &'static str) -> Parameter {
+    Parameter::new(name, Kind::Integer)
+}
+
+const fn real(name: &'static str) -> Parameter {
+    Parameter::new(name, Kind::Real)
+}
+
+const fn logical(name: &'static str) -> Parameter {
+    Parameter::new(name, Kind::Logical)
+}
+
+/// A namelist group and its parameters, sorted by name.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Group {
+    pub name: &'static str,
+    pub parameters: &'static [Parameter],
+}
+
+impl Group {
+    pub fn parameter(&self, name: &str) -> Option<&'static Parameter> {
+        self.parameters
+            .binary_search_by(|parameter| parameter.name.cmp(name))
+            .ok()
+            .map(|i| &self.parameters[i])
+    }
+}
+
+/// Namelist groups which FDS accepts but which are not in the catalog. The
+/// last nine are those of FDS+Evac, which FDS 6 reads up to FDS 6.7 when built
+/// with evacuation support.
+pub const UNCATALOGUED_GROUPS: &[&str] = &[
+    "CATF", "COMB", "GEOM", "MOVE", "RADF", "SM3D", "TAIL", "WIND", "CORR", "DOOR", "EDEV", "ENTR",
+    "EVAC", "EVHO", "EXIT", "PERS", "STRS",
+];
+
+/// The namelist group with a given name.
+pub fn group(name: &str) -> Option<&'static Group> {
+    GROUPS.iter().find(|group| group.name == name)
+}
+
+/// A parameter of a namelist group.
+pub fn parameter(group_name: &str, name: &str) -> Option<&'static Parameter> {
+    group(group_name).and_then(|group| group.parameter(name))
+}
+
+fn default_value(group_name: &str, name: &str) -> Value {
+    parameter(group_name, name)
+        .and_then(|parameter| parameter.default)
+        .unwrap_or_else(|| panic!("{} {} has no default in the catalog", group_name, name))
+}
+
+pub(crate) fn default_real(group_name: &str, name: &str) -> f64 {
+    match default_value(group_name, name) {
+        Value::Real(x) => x,
+        value => panic!("{} {} default is not real: {:?}", group_name, name, value),
+    }
+}
+
+pub(crate) fn default_integer(group_name: &str, name: &str) -> i64 {
+    match default_value(group_name, name) {
+        Value::Integer(x) => x,
+        value => panic!(
+            "{} {} default is not an integer: {:?}",
+            group_name, name, value
+        ),
+    }
+}
+
+pub(crate) fn default_logical(group_name: &str, name: &str) -> bool {
+    match default_value(group_name, name) {
+        Value::Logical(x) => x,
+        value => panic!(
+            "{} {} default is not logical: {:?}",
+            group_name, name, value
+        ),
+    }
+}
+
+pub(crate) fn default_character(group_name: &str, name: &str) -> &'static str {
+    match default_value(group_name, name) {
+        Value::Character(x) => x,
+        value => panic!(
+            "{} {} default is not character: {:?}",
+            group_name, name, value
+        ),
+    }
+}
+
+pub(crate) fn default_reals(group_name: &str, name: &str) -> &'static [f64] {
+    match default_value(group_name, name) {
+        Value::Reals(x) => x,
+        value => panic!("{} {} default is not real: {:?}", group_name, name, value),
+    }
+}
+
+pub(crate) fn default_integers(group_name: &str, name: &str) -> &'static [i64] {
+    match default_value(group_name, name) {
+        Value::Integers(x) => x,
+        value => panic!(
+            "{} {} default is not an integer: {:?}",
+            group_name, name, value
+        ),
+    }
+}
+
+pub static GROUPS: &[Group] = &[
+    Group {
+        name: "BNDE",
+        parameters: BNDE,
+    },
+    Group {
+        name: "BNDF",
+        parameters: BNDF,
+    },
+    Group {
+        name: "CLIP",
+        parameters: CLIP,
+    },
+    Group {
+        name: "CSVF",
+        parameters: CSVF,
+    },
+    Group {
+        name: "CTRL",
+        parameters: CTRL,
+    },
+    Group {
+        name: "DEVC",
+        parameters: DEVC,
+    },
+    Group {
+        name: "DUMP",
+        parameters: DUMP,
+    },
+    Group {
+        name: "HEAD",
+        parameters: HEAD,
+    },
+    Group {
+        name: "HOLE",
+        parameters: HOLE,
+    },
+    Group {
+        name: "HVAC",
+        parameters: HVAC,
+    },
+    Group {
+        name: "INIT",
+        parameters: INIT,
+    },
+    Group {
+        name: "ISOF",
+        parameters: ISOF,
+    },
+    Group {
+        name: "MATL",
+        parameters: MATL,
+    },
+    Group {
+        name: "MESH",
+        parameters: MESH,
+    },
+    Group {
+        name: "MISC",
+        parameters: MISC,
+    },
+    Group {
+        name: "MULT",
+        parameters: MULT,
+    },
+    Group {
+        name: "OBST",
+        parameters: OBST,
+    },
+    Group {
+        name: "PART",
+        parameters: PART,
+    },
+    Group {
+        name: "PRES",
+        parameters: PRES,
+    },
+    Group {
+        name: "PROF",
+        parameters: PROF,
+    },
+    Group {
+        name: "PROP",
+        parameters: PROP,
+    },
+    Group {
+        name: "RADI",
+        parameters: RADI,
+    },
+    Group {
+        name: "RAMP",
+        parameters: RAMP,
+    },
+    Group {
+        name: "REAC",
+        parameters: REAC,
+    },
+    Group {
+        name: "SLCF",
+        parameters: SLCF,
+    },
+    Group {
+        name: "SPEC",
+        parameters: SPEC,
+    },
+    Group {
+        name: "SURF",
+        parameters: SURF,
+    },
+    Group {
+        name: "TABL",
+        parameters: TABL,
+    },
+    Group {
+        name: "TIME",
+        parameters: TIME,
+    },
+    Group {
+        name: "TRNX",
+        parameters: TRNX,
+    },
+    Group {
+        name: "TRNY",
+        parameters: TRNY,
+    },
+    Group {
+        name: "TRNZ",
+        parameters: TRNZ,
+    },
+    Group {
+        name: "VENT",
+        parameters: VENT,
+    },
+    Group {
+        name: "ZONE",
+        parameters: ZONE,
+    },
+];
+
+const BNDE: &[Parameter] = &[
+    logical("CELL_CENTERED").default(Value::Logical(false)),
+    character("FYI"),
+    character("PART_ID"),
+    character("PROP_ID"),
+    character("QUANTITY"),
+    character("SPEC_ID"),
+];
+
+const BNDF: &[Parameter] = &[
+    logical("CELL_CENTERED").default(Value::Logical(false)),
+    character("FYI"),
+    character("MATL_ID"),
+    character("PART_ID"),
+    character("PROP_ID"),
+    character("QUANTITY"),
+    logical("RECOUNT_DRIP").default(Value::Logical(false)),
+    character("SPEC_ID"),
+    character("STATISTICS"),
+    character("TEMPORAL_STATISTIC").since(6, 7),
+];
+
+const CLIP: &[Parameter] = &[
+    integer("CLIP_DT_RESTRICTIONS_MAX"),
+    character("FYI"),
+    real("MAXIMUM_DENSITY").units("kg/m³"),
+    real("MAXIMUM_MASS_FRACTION").matrix(),
+    real("MAXIMUM_TEMPERATURE").units("°C"),
+    real("MINIMUM_DENSITY").units("kg/m³"),
+    real("MINIMUM_MASS_FRACTION").matrix(),
+    real("MINIMUM_TEMPERATURE").units("°C"),
+];
+
+const CSVF: &[Parameter] = &[character("CSVFILE"), character("UVWFILE")];
+
+const CTRL: &[Parameter] = &[
+    real("CONSTANT"),
+    integer("CYCLES"),
+    real("CYCLE_TIME"),
+    real("DELAY").default(Value::Real(0.0)).units("s").min(0.0),
+    real("DIFFERENTIAL_GAIN").default(Value::Real(0.0)),
+    logical("EVACUATION").until(6, 7),
+    character("FUNCTION_TYPE"),
+    character("FYI"),
+    character("ID"),
+    logical("INITIAL_STATE").default(Value::Logical(false)),
+    character("INPUT_ID").vector(),
+    real("INTEGRAL_GAIN").default(Value::Real(0.0)),
+    logical("LATCH").default(Value::Logical(true)),
+    integer("N").default(Value::Integer(1)),
+    character("ON_BOUND").default(Value::Character("LOWER")),
+    real("PROPORTIONAL_GAIN").default(Value::Real(1.0)),
+    character("RAMP_ID"),
+    real("SETPOINT"),
+    real("TARGET_VALUE").default(Value::Real(0.0)),
+    integer("TRIP_DIRECTION")
+        .default(Value::Integer(1))
+        .min(-1.0)
+        .max(1.0),
+];
+
+const DEVC: &[Parameter] = &[
+    logical("ABSOLUTE_VALUE"),
+    real("BYPASS_FLOWRATE"),
+    real("CONVERSION_ADDEND").default(Value::Real(0.0)),
+    real("CONVERSION_FACTOR").default(Value::Real(1.0)),
+    real("COORD_FACTOR"),
+    character("CTRL_ID"),
+    character("DB"),
+    real("DELAY").default(Value::Real(0.0)).units("s").min(0.0),
+    real("DEPTH").units("m").min(0.0),
+    character("DEVC_ID"),
+    logical("DRY").default(Value::Logical(false)),
+    character("DUCT_ID"),
+    character("D_ID"),
+    logical("EVACUATION").until(6, 7),
+    real("FLOWRATE"),
+    real("FORCE_DIRECTION").array(3),
+    character("FYI"),
+    logical("HIDE_COORDINATES").default(Value::Logical(false)),
+    character("ID"),
+    logical("INITIAL_STATE").default(Value::Logical(false)),
+    character("INIT_ID"),
+    integer("IOR").min(-3.0).max(3.0),
+    logical("LATCH").default(Value::Logical(true)),
+    integer("LP_TAG"),
+    character("MATL_ID"),
+    character("MOVE_ID"),
+    character("NODE_ID").vector(),
+    character("NO_UPDATE_CTRL_ID"),
+    character("NO_UPDATE_DEVC_ID"),
+    integer("N_INTERVALS"),
+    real("ORIENTATION")
+        .array(3)
+        .default(Value::Reals(&[0.0, 0.0, -1.0])),
+    integer("ORIENTATION_NUMBER"),
+    logical("OUTPUT").default(Value::Logical(true)),
+    character("PART_ID"),
+    integer("PIPE_INDEX"),
+    integer("POINTS").default(Value::Integer(1)).min(1.0),
+    real("POINTS_ARRAY_X").vector(),
+    real("POINTS_ARRAY_Y").vector(),
+    real("POINTS_ARRAY_Z").vector(),
+    character("PROP_ID"),
+    character("QUANTITY"),
+    character("QUANTITY2"),
+    real("QUANTITY_RANGE").array(2),
+    character("REAC_ID"),
+    logical("RELATIVE").default(Value::Logical(false)),
+    real("ROTATION").default(Value::Real(0.0)).units("°"),
+    character("R_ID"),
+    real("SETPOINT"),
+    real("SMOOTHING_FACTOR")
+        .default(Value::Real(0.0))
+        .min(0.0)
+        .max(1.0),
+    character("SPATIAL_STATISTIC"),
+    character("SPEC_ID"),
+    character("STATISTICS"),
+    real("STATISTICS_END").units("s"),
+    real("STATISTICS_START"),
+    character("SURF_ID"),
+    character("TEMPORAL_STATISTIC").since(6, 7),
+    logical("TIME_AVERAGED").default(Value::Logical(true)),
+    logical("TIME_HISTORY").default(Value::Logical(false)),
+    real("TIME_PERIOD").units("s"),
+    integer("TRIP_DIRECTION")
+        .default(Value::Integer(1))
+        .min(-1.0)
+        .max(1.0),
+    character("UNITS"),
+    integer("VELO_INDEX"),
+    real("XB").array(6).units("m"),
+    real("XYZ").array(3).units("m"),
+    character("XYZ_UNITS"),
+    character("X_ID"),
+    character("Y_ID"),
+    character("Z_ID"),
+];
+
+const DUMP: &[Parameter] = &[
+    logical("CFL_FILE"),
+    logical("CLIP_RESTART_FILES"),
+    logical("COLUMN_DUMP_LIMIT").default(Value::Logical(false)),
+    integer("CTRL_COLUMN_LIMIT")
+        .default(Value::Integer(254))
+        .min(1.0),
+    integer("DEVC_COLUMN_LIMIT")
+        .default(Value::Integer(254))
+        .min(1.0),
+    integer("DIAGNOSTICS_INTERVAL"),
+    real("DT_BNDE").units("s").min(0.0),
+    real("DT_BNDF").units("s").min(0.0),
+    real("DT_CPU").units("s").min(0.0),
+    real("DT_CTRL").units("s").min(0.0),
+    real("DT_DEVC").units("s").min(0.0),
+    real("DT_DEVC_LINE").units("s").min(0.0),
+    real("DT_FLUSH").units("s").min(0.0),
+    real("DT_GEOM").units("s").min(0.0),
+    real("DT_HRR").units("s").min(0.0),
+    real("DT_ISOF").units("s").min(0.0),
+    real("DT_MASS").units("s").min(0.0),
+    real("DT_PART").units("s").min(0.0),
+    real("DT_PL3D").units("s").min(0.0),
+    real("DT_PROF").units("s").min(0.0),
+    real("DT_RADF").units("s"),
+    real("DT_RESTART").units("s").min(0.0),
+    real("DT_SL3D").units("s").min(0.0),
+    real("DT_SLCF").units("s").min(0.0),
+    real("DT_SMOKE3D").units("s"),
+    real("DT_UVW").units("s"),
+    logical("EB_PART_FILE"),
+    logical("FLUSH_FILE_BUFFERS").default(Value::Logical(true)),
+    logical("GEOM_DIAG"),
+    logical("MASS_FILE").default(Value::Logical(false)),
+    integer("MAXIMUM_PARTICLES")
+        .default(Value::Integer(1000000))
+        .min(0.0),
+    real("MMS_TIMER"),
+    integer("NFRAMES").default(Value::Integer(1000)).min(1.0),
+    character("PLOT3D_PART_ID").vector(),
+    character("PLOT3D_QUANTITY").vector(),
+    character("PLOT3D_SPEC_ID").vector(),
+    integer("PLOT3D_VELO_INDEX").vector(),
+    character("RAMP_BNDF"),
+    character("RAMP_CPU"),
+    character("RAMP_CTRL"),
+    character("RAMP_DEVC"),
+    character("RAMP_FLUSH"),
+    character("RAMP_GEOM"),
+    character("RAMP_HRR"),
+    character("RAMP_ISOF"),
+    character("RAMP_MASS"),
+    character("RAMP_PART"),
+    character("RAMP_PL3D"),
+    character("RAMP_PROF"),
+    character("RAMP_RADF"),
+    character("RAMP_RESTART"),
+    character("RAMP_SL3D"),
+    character("RAMP_SLCF"),
+    character("RAMP_SMOKE3D"),
+    character("RAMP_UVW"),
+    character("RENDER_FILE"),
+    character("RESULTS_DIR"),
+    integer("SIG_FIGS").default(Value::Integer(8)).min(1.0),
+    integer("SIG_FIGS_EXP").default(Value::Integer(3)).min(1.0),
+    logical("SMOKE3D").default(Value::Logical(true)),
+    character("SMOKE3D_QUANTITY"),
+    character("SMOKE3D_SPEC_ID"),
+    logical("STATUS_FILES").default(Value::Logical(false)),
+    logical("SUPPRESS_DIAGNOSTICS"),
+    real("UVW_TIMER").vector(),
+    logical("VELOCITY_ERROR_FILE"),
+    logical("WRITE_XYZ").default(Value::Logical(false)),
+];
+
+const HEAD: &[Parameter] = &[character("CHID"), character("FYI"), character("TITLE")];
+
+const HOLE: &[Parameter] = &[
+    character("COLOR"),
+    character("CTRL_ID"),
+    character("DEVC_ID"),
+    logical("EVACUATION").until(6, 7),
+    character("FYI"),
+    character("ID"),
+    character("MESH_ID"),
+    character("MULT_ID"),
+    integer("RGB").array(3).min(0.0).max(255.0),
+    real("TRANSPARENCY")
+        .default(Value::Real(1.0))
+        .min(0.0)
+        .max(1.0),
+    real("XB").array(6).units("m"),
+];
+
+const HVAC: &[Parameter] = &[
+    character("AIRCOIL_ID"),
+    logical("AMBIENT").default(Value::Logical(false)),
+    real("AREA").units("m²").min(0.0),
+    real("CLEAN_LOSS"),
+    real("COOLANT_MASS_FLOW").units("kg/s"),
+    real("COOLANT_SPECIFIC_HEAT").units("kJ/(kg·K)").min(0.0),
+    real("COOLANT_TEMPERATURE").units("°C"),
+    character("CTRL_ID"),
+    logical("DAMPER").default(Value::Logical(false)),
+    character("DEVC_ID"),
+    real("DIAMETER").units("m").min(0.0),
+    character("DUCT_ID").vector(),
+    character("DUCT_INTERP_TYPE"),
+    real("EFFICIENCY").vector().min(0.0).max(1.0),
+    character("FAN_ID"),
+    character("FILTER_ID"),
+    real("FIXED_Q").vector().units("kW"),
+    character("ID"),
+    logical("LEAK_ENTHALPY").default(Value::Logical(false)),
+    real("LENGTH").units("m").min(0.0),
+    real("LOADING").vector(),
+    real("LOADING_MULTIPLIER").vector(),
+    real("LOSS").vector(),
+    real("MASS_FLOW").units("kg/s"),
+    real("MAX_FLOW").units("m³/s"),
+    real("MAX_PRESSURE").units("Pa"),
+    character("NODE_ID").vector(),
+    integer("N_CELLS"),
+    real("PERIMETER").units("m").min(0.0),
+    character("RAMP_ID"),
+    character("RAMP_LOSS"),
+    logical("REVERSE").default(Value::Logical(false)),
+    real("ROUGHNESS").units("m").min(0.0),
+    character("SPEC_ID"),
+    real("TAU_AC").default(Value::Real(1.0)).units("s"),
+    real("TAU_FAN").default(Value::Real(1.0)).units("s"),
+    real("TAU_VF").default(Value::Real(1.0)).units("s"),
+    logical("TRANSPORT_PARTICLES"),
+    character("TYPE_ID"),
+    character("VENT2_ID"),
+    character("VENT_ID"),
+    real("VOLUME_FLOW").units("m³/s"),
+    real("XYZ").array(3).units("m"),
+];
+
+const INIT: &[Parameter] = &[
+    real("AUTO_IGNITION_TEMPERATURE").units("°C"),
+    character("BULK_DENSITY_FILE"),
+    logical("CELL_CENTERED"),
+    real("CROWN_BASE_HEIGHT").units("m"),
+    real("CROWN_BASE_WIDTH").units("m"),
+    character("CTRL_ID"),
+    character("DB"),
+    real("DENSITY").units("kg/m³").min(0.0),
+    character("DEVC_ID"),
+    real("DIAMETER").units("µm").min(0.0),
+    logical("DRY"),
+    real("DT_INSERT").units("s").min(0.0),
+    real("DX").units("m"),
+    real("DY").units("m"),
+    real("DZ").units("m"),
+    real("HEIGHT").units("m").min(0.0),
+    real("HRRPUV").units("kW/m³"),
+    character("ID"),
+    real("INNER_RADIUS").units("m"),
+    real("MASS_FRACTION").vector(),
+    real("MASS_PER_TIME").units("kg/s").min(0.0),
+    real("MASS_PER_VOLUME").units("kg/m³").min(0.0),
+    character("MULT_ID"),
+    integer("NUMBER_INITIAL_PARTICLES"),
+    integer("N_PARTICLES"),
+    integer("N_PARTICLES_PER_CELL"),
+    real("PARTICLE_WEIGHT_FACTOR"),
+    character("PART_ID"),
+    character("PATH_RAMP").array(3),
+    real("RADIUS").units("m").min(0.0),
+    character("RAMP_Q"),
+    character("SHAPE").default(Value::Character("BLOCK")),
+    character("SPEC_ID").vector(),
+    real("TEMPERATURE").units("°C"),
+    real("TREE_HEIGHT").units("m"),
+    logical("UNIFORM"),
+    real("UVW").array(3).units("m/s"),
+    real("VOLUME_FRACTION").vector(),
+    real("XB").array(6).units("m"),
+    real("XYZ").array(3).units("m"),
+];
+
+const ISOF: &[Parameter] = &[
+    character("FYI"),
+    character("QUANTITY"),
+    integer("REDUCE_TRIANGLES"),
+    character("SPEC_ID"),
+    real("VALUE").vector(),
+    integer("VELO_INDEX").default(Value::Integer(0)),
+];
+
+const MATL: &[Parameter] = &[
+    real("A").vector().units("1/s").min(0.0),
+    real("ABSORPTION_COEFFICIENT")
+        .default(Value::Real(50000.0))
+        .units("1/m")
+        .min(0.0),
+    logical("ADJUST_H"),
+    logical("ALLOW_SHRINKING"),
+    logical("ALLOW_SWELLING"),
+    real("BETA_CHAR").vector(),
+    real("BOILING_TEMPERATURE").units("°C"),
+    character("COLOR"),
+    real("CONDUCTIVITY").units("W/(m·K)").min(0.0),
+    character("CONDUCTIVITY_RAMP"),
+    real("DENSITY").units("kg/m³").min(0.0),
+    real("E").vector().units("J/mol").min(0.0),
+    real("EMISSIVITY")
+        .default(Value::Real(0.9))
+        .min(0.0)
+        .max(1.0),
+    character("FYI"),
+    real("GAS_DIFFUSION_DEPTH").vector(),
+    real("HEATING_RATE")
+        .vector()
+        .default(Value::Reals(&[5.0]))
+        .units("K/min"),
+    real("HEAT_OF_COMBUSTION").vector().units("kJ/kg"),
+    real("HEAT_OF_REACTION").vector().units("kJ/kg"),
+    character("ID"),
+    character("MATL_ID").vector(),
+    real("MAX_REACTION_RATE").vector(),
+    real("MW").units("g/mol"),
+    real("NU_MATL").vector(),
+    real("NU_O2_CHAR").vector(),
+    real("NU_PART").matrix(),
+    real("NU_SPEC").vector(),
+    real("N_O2").vector(),
+    integer("N_REACTIONS"),
+    real("N_S").vector(),
+    real("N_T").vector(),
+    character("PART_ID").matrix(),
+    logical("PCR").vector(),
+    real("POROSITY"),
+    real("PYROLYSIS_RANGE")
+        .vector()
+        .default(Value::Reals(&[80.0]))
+        .units("°C"),
+    real("REFERENCE_ENTHALPY").units("kJ/kg"),
+    real("REFERENCE_ENTHALPY_TEMPERATURE").units("°C"),
+    real("REFERENCE_RATE").vector(),
+    real("REFERENCE_TEMPERATURE").vector().units("°C"),
+    integer("RGB").array(3).min(0.0).max(255.0),
+    real("SPECIFIC_HEAT").units("kJ/(kg·K)").min(0.0),
+    character("SPECIFIC_HEAT_RAMP"),
+    character("SPEC_ID").vector(),
+    real("THRESHOLD_SIGN").vector(),
+    real("THRESHOLD_TEMPERATURE").vector().units("°C"),
+];
+
+const MESH: &[Parameter] = &[
+    logical("CHECK_MESH_ALIGNMENT").default(Value::Logical(false)),
+    character("COLOR"),
+    logical("CYLINDRICAL").default(Value::Logical(false)),
+    logical("EVACUATION").until(6, 7),
+    logical("EVAC_HUMANS").until(6, 7),
+    real("EVAC_Z_OFFSET")
+        .default(Value::Real(1.0))
+        .units("m")
+        .until(6, 7),
+    character("FYI"),
+    character("ID"),
+    integer("IJK")
+        .array(3)
+        .default(Value::Integers(&[10, 10, 10]))
+        .min(1.0),
+    integer("LEVEL").default(Value::Integer(1)),
+    integer("MPI_PROCESS").min(0.0),
+    character("MULT_ID"),
+    integer("N_THREADS").min(1.0),
+    character("PERIODIC_MESH_IDS").vector(),
+    integer("RGB").array(3).min(0.0).max(255.0),
+    real("XB")
+        .array(6)
+        .default(Value::Reals(&[0.0, 1.0, 0.0, 1.0, 0.0, 1.0]))
+        .units("m"),
+];
+
+const MISC: &[Parameter] = &[
+    logical("AEROSOL_AL2O3"),
+    logical("AGGLOMERATION"),
+    real("ALIGNMENT_TOLERANCE"),
+    logical("ALLOW_SURFACE_PARTICLES"),
+    logical("ALLOW_UNDERSIDE_PARTICLES"),
+    real("ASSUMED_GAS_TEMPERATURE").units("°C"),
+    character("ASSUMED_GAS_TEMPERATURE_RAMP"),
+    logical("BAROCLINIC").default(Value::Logical(true)),
+    logical("BNDF_DEFAULT"),
+    logical("CC_IBM"),
+    real("CFL_MAX").default(Value::Real(1.0)).min(0.0),
+    real("CFL_MIN").default(Value::Real(0.8)).min(0.0),
+    integer("CFL_VELOCITY_NORM"),
+    logical("CHECK_HT"),
+    logical("CHECK_REALIZABILITY"),
+    logical("CHECK_VN"),
+    logical("CLIP_MASS_FRACTION"),
+    real("CNF_CUTOFF"),
+    logical("COMPUTE_VISCOSITY_TWICE"),
+    logical("COMPUTE_ZETA_SOURCE_TERM"),
+    logical("CONSTANT_H_SOLID"),
+    logical("CONSTANT_SPECIFIC_HEAT_RATIO"),
+    real("CORIOLIS_VECTOR").array(3),
+    logical("CORRECT_SUBGRID_TEMPERATURE"),
+    logical("COUPLED_1D3D_HEAT_TRANSFER"),
+    real("C_DEARDORFF").default(Value::Real(0.1)),
+    real("C_RNG"),
+    real("C_RNG_CUTOFF"),
+    real("C_SMAGORINSKY").default(Value::Real(0.2)),
+    real("C_VREMAN").default(Value::Real(0.07)),
+    real("C_WALE"),
+    logical("DEPOSITION"),
+    logical("DNS").until(6, 0),
+    real("DRAG_CFL_MAX"),
+    real("DT_EXTERNAL").units("s"),
+    real("DT_EXTERNAL_HEARTBEAT").units("s"),
+    real("DT_HVAC"),
+    real("DT_MEAN_FORCING"),
+    logical("ENTHALPY_TRANSPORT"),
+    logical("EVACUATION_DRILL").until(6, 7),
+    logical("EVACUATION_MC_MODE").until(6, 7),
+    integer("EVAC_PRESSURE_ITERATIONS").until(6, 7),
+    character("EVAC_SURF_DEFAULT").until(6, 7),
+    integer("EVAC_TIME_ITERATIONS").until(6, 7),
+    logical("EVAPORATION"),
+    logical("EXCHANGE_EDGES"),
+    logical("EXTERNAL_BOUNDARY_CORRECTION"),
+    character("EXTERNAL_FILENAME"),
+    character("EXTINCTION_MODEL"),
+    logical("FDS5_OPTIONS"),
+    integer("FLUX_LIMITER"),
+    real("FORCE_VECTOR").array(3),
+    logical("FREEZE_VELOCITY"),
+    character("FYI"),
+    real("GAMMA"),
+    logical("GRAVITATIONAL_DEPOSITION"),
+    logical("GRAVITATIONAL_SETTLING"),
+    real("GROUND_LEVEL").units("m"),
+    real("GVEC")
+        .array(3)
+        .default(Value::Reals(&[0.0, 0.0, -9.81]))
+        .units("m/s²"),
+    real("HRRPUV_MAX_SMV"),
+    integer("HT3D_TEST"),
+    real("HUMIDITY")
+        .default(Value::Real(40.0))
+        .units("%")
+        .min(0.0)
+        .max(100.0),
+    logical("HVAC_LOCAL_PRESSURE"),
+    logical("HVAC_MASS_TRANSPORT"),
+    real("HVAC_PRES_RELAX"),
+    logical("HVAC_QFAN"),
+    real("H_F_REFERENCE_TEMPERATURE")
+        .default(Value::Real(25.0))
+        .units("°C"),
+    logical("IBLANK_SMV"),
+    integer("IMMERSED_BOUNDARY_METHOD"),
+    real("INITIAL_UNMIXED_FRACTION"),
+    logical("KINETIC_ENERGY_SOURCE"),
+    real("LAPSE_RATE").default(Value::Real(0.0)).units("K/m"),
+    character("LES_FILTER_WIDTH"),
+    logical("LEVEL_SET_ELLIPSE"),
+    integer("LEVEL_SET_MODE"),
+    real("MAXIMUM_VISIBILITY")
+        .default(Value::Real(30.0))
+        .units("m")
+        .min(0.0),
+    integer("MAX_CHEMISTRY_ITERATIONS"),
+    integer("MAX_LEAK_PATHS"),
+    integer("MAX_RAMPS"),
+    logical("MEAN_FORCING").array(3),
+    real("MINIMUM_ZONE_VOLUME").units("m³"),
+    real("MPI_TIMEOUT"),
+    character("NEAR_WALL_TURBULENCE_MODEL"),
+    real("NEIGHBOR_SEPARATION_DISTANCE").units("m"),
+    logical("NEW_MOMENTUM_NUDGING"),
+    logical("NEW_OPEN_BOUNDARY"),
+    logical("NOISE").default(Value::Logical(true)),
+    real("NOISE_VELOCITY")
+        .default(Value::Real(0.005))
+        .units("m/s"),
+    real("NORTHANGLE"),
+    real("NORTH_BEARING").units("°"),
+    logical("NO_EVACUATION").until(6, 7),
+    logical("NO_RAMPS"),
+    integer("N_FIXED_CHEMISTRY_SUBSTEPS"),
+    real("ORIGIN_LAT").units("°"),
+    real("ORIGIN_LON").units("°"),
+    logical("OVERWRITE"),
+    logical("PARTICLE_CFL"),
+    real("PARTICLE_CFL_MAX"),
+    real("PARTICLE_CFL_MIN"),
+    integer("PERIODIC_TEST"),
+    logical("POROUS_FLOOR"),
+    logical("POSITIVE_ERROR_TEST"),
+    logical("POTENTIAL_TEMPERATURE_CORRECTION"),
+    real("PR").default(Value::Real(0.5)).min(0.0),
+    logical("PROCESS_ALL_MESHES"),
+    logical("PROFILING"),
+    logical("PROJECTION"),
+    real("P_INF")
+        .default(Value::Real(101325.0))
+        .units("Pa")
+        .min(0.0),
+    logical("RADIATION"),
+    character("RAMP_FVX_T"),
+    character("RAMP_FVY_T"),
+    character("RAMP_FVZ_T"),
+    character("RAMP_GX"),
+    character("RAMP_GY"),
+    character("RAMP_GZ"),
+    character("RAMP_U0"),
+    character("RAMP_U0_T"),
+    character("RAMP_U0_Z"),
+    character("RAMP_V0"),
+    character("RAMP_V0_T"),
+    character("RAMP_V0_Z"),
+    character("RAMP_W0"),
+    character("RAMP_W0_T"),
+    character("RAMP_W0_Z"),
+    logical("RESEARCH_MODE"),
+    logical("RESTART").default(Value::Logical(false)),
+    character("RESTART_CHID"),
+    real("RICHARDSON_ERROR_TOLERANCE"),
+    real("RUN_AVG_FAC"),
+    real("SC").default(Value::Real(0.5)).min(0.0),
+    logical("SECOND_ORDER_INTERPOLATED_BOUNDARY"),
+    logical("SECOND_ORDER_PARTICLE_TRANSPORT"),
+    logical("SHARED_FILE_SYSTEM"),
+    character("SIMULATION_MODE")
+        .default(Value::Character("VLES"))
+        .since(6, 1),
+    integer("SLIP_CONDITION"),
+    real("SMOKE_ALBEDO")
+        .default(Value::Real(0.3))
+        .min(0.0)
+        .max(1.0),
+    logical("SOLID_PHASE_ONLY"),
+    real("SOOT_DENSITY").units("kg/m³"),
+    logical("SOOT_OXIDATION"),
+    real("SPONGE_LAYER_DISTANCE"),
+    logical("STRATIFICATION").default(Value::Logical(true)),
+    logical("SUPPRESSION").default(Value::Logical(true)),
+    character("SURF_DEFAULT"),
+    real("TAU_DEFAULT").units("s"),
+    logical("TEMPERATURE_DEPENDENT_REACTION"),
+    logical("TENSOR_DIFFUSIVITY"),
+    logical("TERRAIN_CASE"),
+    character("TERRAIN_IMAGE"),
+    logical("TEST_FILTER_QUADRATURE"),
+    real("TEXTURE_ORIGIN").array(3),
+    logical("THERMOPHORETIC_DEPOSITION"),
+    logical("THERMOPHORETIC_SETTLING"),
+    logical("THICKEN_OBSTRUCTIONS").default(Value::Logical(false)),
+    real("TMPA")
+        .default(Value::Real(20.0))
+        .units("°C")
+        .min(-273.15),
+    logical("TRANSPORT_UNMIXED_FRACTION"),
+    logical("TRANSPORT_ZETA_SCHEME"),
+    character("TURBULENCE_MODEL"),
+    logical("TURBULENT_DEPOSITION"),
+    real("TURB_INIT_CLOCK"),
+    real("U0").default(Value::Real(0.0)).units("m/s"),
+    character("UVW_FILE"),
+    real("V0").default(Value::Real(0.0)).units("m/s"),
+    logical("VEG_LEVEL_SET_COUPLED"),
+    logical("VEG_LEVEL_SET_UNCOUPLED"),
+    logical("VERBOSE"),
+    real("VISIBILITY_FACTOR").default(Value::Real(3.0)).min(0.0),
+    real("VN_MAX").default(Value::Real(1.0)).min(0.0),
+    real("VN_MIN").default(Value::Real(0.8)).min(0.0),
+    real("W0").default(Value::Real(0.0)).units("m/s"),
+    real("WD_PROPS").vector(),
+    logical("WIND_BOUNDARY"),
+    logical("WIND_ONLY"),
+    real("Y_CO2_INFTY"),
+    real("Y_O2_INFTY"),
+];
+
+const MULT: &[Parameter] = &[
+    real("DX").default(Value::Real(0.0)).units("m"),
+    real("DX0").default(Value::Real(0.0)).units("m"),
+    real("DXB").array(6).units("m"),
+    real("DY").default(Value::Real(0.0)).units("m"),
+    real("DY0").default(Value::Real(0.0)).units("m"),
+    real("DYB").array(6).units("m"),
+    real("DZ").default(Value::Real(0.0)).units("m"),
+    real("DZ0").default(Value::Real(0.0)).units("m"),
+    real("DZB").array(6).units("m"),
+    character("ID"),
+    integer("I_LOWER").default(Value::Integer(0)),
+    integer("I_LOWER_SKIP"),
+    integer("I_UPPER").default(Value::Integer(0)),
+    integer("I_UPPER_SKIP"),
+    integer("J_LOWER").default(Value::Integer(0)),
+    integer("J_LOWER_SKIP"),
+    integer("J_UPPER").default(Value::Integer(0)),
+    integer("J_UPPER_SKIP"),
+    integer("K_LOWER").default(Value::Integer(0)),
+    integer("K_LOWER_SKIP"),
+    integer("K_UPPER").default(Value::Integer(0)),
+    integer("K_UPPER_SKIP"),
+    integer("N_LOWER").default(Value::Integer(0)),
+    integer("N_UPPER").default(Value::Integer(0)),
+];
+
+const OBST: &[Parameter] = &[
+    logical("ALLOW_VENT").default(Value::Logical(true)),
+    logical("BNDF_FACE").vector(),
+    logical("BNDF_OBST").default(Value::Logical(true)),
+    real("BULK_DENSITY").units("kg/m³").min(0.0),
+    character("COLOR"),
+    character("CTRL_ID"),
+    character("DEVC_ID"),
+    logical("EVACUATION").until(6, 7),
+    character("FYI"),
+    real("HEIGHT").units("m"),
+    logical("HT3D"),
+    character("ID"),
+    real("LENGTH").units("m"),
+    character("MATL_ID"),
+    character("MESH_ID"),
+    character("MOVE_ID"),
+    character("MULT_ID"),
+    logical("NOTERRAIN"),
+    real("ORIENTATION").array(3),
+    logical("OUTLINE").default(Value::Logical(false)),
+    logical("OVERLAY"),
+    logical("PERMIT_HOLE").default(Value::Logical(true)),
+    character("PROP_ID"),
+    real("RADIUS").units("m"),
+    logical("REMOVABLE").default(Value::Logical(true)),
+    integer("RGB").array(3).min(0.0).max(255.0),
+    logical("SAWTOOTH").default(Value::Logical(true)),
+    character("SHAPE"),
+    character("SURF_ID"),
+    character("SURF_ID6").array(6),
+    character("SURF_IDS").array(3),
+    real("TEXTURE_ORIGIN").array(3).units("m"),
+    real("THETA").units("°"),
+    logical("THICKEN").default(Value::Logical(false)),
+    real("TRANSPARENCY")
+        .default(Value::Real(1.0))
+        .min(0.0)
+        .max(1.0),
+    real("WIDTH").units("m"),
+    real("XB").array(6).units("m"),
+    real("XYZ").array(3).units("m"),
+];
+
+const PART: &[Parameter] = &[
+    logical("ADHERE_TO_SOLID"),
+    real("AGE").units("s").min(0.0),
+    logical("BREAKUP"),
+    character("BREAKUP_CNF_RAMP_ID"),
+    character("BREAKUP_DISTRIBUTION"),
+    real("BREAKUP_GAMMA_D"),
+    real("BREAKUP_RATIO"),
+    real("BREAKUP_SIGMA_D"),
+    logical("CHECK_DISTRIBUTION"),
+    character("CNF_RAMP_ID"),
+    character("COLOR"),
+    real("COMPLEX_REFRACTIVE_INDEX"),
+    character("CTRL_ID"),
+    real("DENSE_VOLUME_FRACTION"),
+    character("DEVC_ID"),
+    real("DIAMETER").units("µm").min(0.0),
+    character("DISTRIBUTION"),
+    real("DRAG_COEFFICIENT").vector(),
+    character("DRAG_LAW"),
+    real("EMBER_DENSITY_THRESHOLD").units("kg/m³"),
+    logical("EMBER_PARTICLE"),
+    real("EMBER_VELOCITY_THRESHOLD").units("m/s"),
+    real("FREE_AREA_FRACTION"),
+    character("FYI"),
+    real("GAMMA_D"),
+    real("HEAT_OF_COMBUSTION").units("kJ/kg"),
+    real("HORIZONTAL_VELOCITY").units("m/s"),
+    character("ID"),
+    real("INITIAL_TEMPERATURE").units("°C"),
+    logical("MASSLESS").default(Value::Logical(false)),
+    real("MAXIMUM_DIAMETER").units("µm").min(0.0),
+    real("MINIMUM_DIAMETER").units("µm").min(0.0),
+    logical("MONODISPERSE").default(Value::Logical(false)),
+    integer("N_STRATA"),
+    real("ORIENTATION").vector(),
+    logical("PERIODIC_X"),
+    logical("PERIODIC_Y"),
+    logical("PERIODIC_Z"),
+    real("PERMEABILITY").vector(),
+    real("POROUS_VOLUME_FRACTION"),
+    real("PRIMARY_BREAKUP_DRAG_REDUCTION_FACTOR"),
+    real("PRIMARY_BREAKUP_TIME").units("s"),
+    character("PROP_ID"),
+    character("QUANTITIES").vector(),
+    character("QUANTITIES_SPEC_ID").vector(),
+    character("RADIATIVE_PROPERTY_TABLE"),
+    real("REAL_REFRACTIVE_INDEX"),
+    integer("RGB").array(3).min(0.0).max(255.0),
+    real("RUNNING_AVERAGE_FACTOR"),
+    real("RUNNING_AVERAGE_FACTOR_WALL"),
+    integer("SAMPLING_FACTOR")
+        .default(Value::Integer(1))
+        .min(1.0),
+    logical("SECOND_ORDER_PARTICLE_TRANSPORT"),
+    real("SHAPE_FACTOR"),
+    real("SIGMA_D"),
+    character("SPEC_ID"),
+    logical("STATIC").default(Value::Logical(false)),
+    real("SURFACE_TENSION").units("N/m").min(0.0),
+    character("SURF_ID"),
+    logical("TARGET_ONLY"),
+    logical("TURBULENT_DISPERSION"),
+    real("VERTICAL_VELOCITY").units("m/s"),
+];
+
+const PRES: &[Parameter] = &[
+    logical("CHECK_POISSON"),
+    integer("FISHPAK_BC").vector(),
+    character("GLMAT_SOLVER"),
+    real("ITERATION_SUSPEND_FACTOR"),
+    logical("LAPLACE_PRESSURE_CORRECTION"),
+    integer("MAX_PRESSURE_ITERATIONS")
+        .default(Value::Integer(10))
+        .min(1.0),
+    real("PRESSURE_RELAX_TIME"),
+    real("PRESSURE_TOLERANCE"),
+    real("RELAXATION_FACTOR"),
+    real("SCARC_ACCURACY"),
+    character("SCARC_COARSE"),
+    real("SCARC_COARSE_ACCURACY"),
+    integer("SCARC_COARSE_ITERATIONS"),
+    character("SCARC_DEBUG"),
+    character("SCARC_INITIAL"),
+    character("SCARC_KRYLOV"),
+    real("SCARC_KRYLOV_ACCURACY"),
+    integer("SCARC_KRYLOV_ITERATIONS"),
+    character("SCARC_METHOD"),
+    character("SCARC_MULTIGRID"),
+    real("SCARC_MULTIGRID_ACCURACY"),
+    character("SCARC_MULTIGRID_COARSENING"),
+    character("SCARC_MULTIGRID_CYCLE"),
+    character("SCARC_MULTIGRID_INTERPOL"),
+    integer("SCARC_MULTIGRID_ITERATIONS"),
+    integer("SCARC_MULTIGRID_LEVEL"),
+    character("SCARC_PRECON"),
+    real("SCARC_PRECON_ACCURACY"),
+    integer("SCARC_PRECON_ITERATIONS"),
+    real("SCARC_PRECON_OMEGA"),
+    character("SCARC_SMOOTH"),
+    real("SCARC_SMOOTH_ACCURACY"),
+    integer("SCARC_SMOOTH_ITERATIONS"),
+    real("SCARC_SMOOTH_OMEGA"),
+    character("SOLVER").default(Value::Character("FFT")),
+    integer("SUSPEND_PRESSURE_ITERATIONS"),
+    logical("TUNNEL_PRECONDITIONER"),
+    real("VELOCITY_TOLERANCE").units("m/s").min(0.0),
+];
+
+const PROF: &[Parameter] = &[
+    integer("FORMAT_INDEX"),
+    character("FYI"),
+    character("ID"),
+    integer("IOR").min(-3.0).max(3.0),
+    character("QUANTITY"),
+    real("XYZ").array(3).units("m"),
+];
+
+const PROP: &[Parameter] = &[
+    real("ACTIVATION_OBSCURATION")
+        .default(Value::Real(3.24))
+        .units("%/m")
+        .min(0.0),
+    real("ACTIVATION_TEMPERATURE").units("°C"),
+    real("ALPHA_C").default(Value::Real(1.8)).units("m"),
+    real("ALPHA_E"),
+    real("BEAD_DENSITY"),
+    real("BEAD_DIAMETER")
+        .default(Value::Real(0.001))
+        .units("m")
+        .min(0.0),
+    real("BEAD_EMISSIVITY")
+        .default(Value::Real(0.85))
+        .min(0.0)
+        .max(1.0),
+    real("BEAD_HEAT_TRANSFER_COEFFICIENT"),
+    real("BEAD_SPECIFIC_HEAT"),
+    real("BETA_C"),
+    real("BETA_E"),
+    real("CHARACTERISTIC_VELOCITY"),
+    real("C_FACTOR")
+        .default(Value::Real(0.0))
+        .units("(m/s)^½")
+        .min(0.0),
+    real("DENSITY"),
+    real("DIAMETER").units("µm").min(0.0),
+    real("DROPLET_VELOCITY"),
+    real("EMISSIVITY"),
+    character("FED_ACTIVITY"),
+    character("FLOW_RAMP"),
+    real("FLOW_RATE").units("L/min").min(0.0),
+    real("FLOW_TAU").default(Value::Real(0.0)).units("s"),
+    character("FYI"),
+    real("GAUGE_EMISSIVITY"),
+    real("GAUGE_TEMPERATURE"),
+    real("HEAT_TRANSFER_COEFFICIENT"),
+    logical("HISTOGRAM"),
+    logical("HISTOGRAM_CUMULATIVE"),
+    real("HISTOGRAM_LIMITS").array(2),
+    integer("HISTOGRAM_NBINS"),
+    logical("HISTOGRAM_NORMALIZE"),
+    character("ID"),
+    real("INITIAL_TEMPERATURE").units("°C"),
+    real("K_FACTOR").units("L/(min·bar^½)").min(0.0),
+    real("LENGTH").default(Value::Real(1.8)).units("m").min(0.0),
+    real("MASS_FLOW_RATE").units("kg/s").min(0.0),
+    real("OFFSET")
+        .default(Value::Real(0.05))
+        .units("m")
+        .min(0.0),
+    real("OPERATING_PRESSURE").units("bar").min(0.0),
+    real("ORIFICE_DIAMETER"),
+    real("P0"),
+    integer("PARTICLES_PER_SECOND"),
+    real("PARTICLE_VELOCITY").units("m/s"),
+    character("PART_ID"),
+    real("PDPA_END"),
+    logical("PDPA_HISTOGRAM"),
+    logical("PDPA_HISTOGRAM_CUMULATIVE"),
+    real("PDPA_HISTOGRAM_LIMITS").array(2),
+    integer("PDPA_HISTOGRAM_NBINS"),
+    logical("PDPA_INTEGRATE"),
+    integer("PDPA_M"),
+    integer("PDPA_N"),
+    logical("PDPA_NORMALIZE"),
+    real("PDPA_RADIUS"),
+    real("PDPA_START"),
+    character("PRESSURE_RAMP"),
+    real("PXX").matrix(),
+    character("QUANTITY"),
+    real("RTI").units("(m·s)^½").min(0.0),
+    character("SMOKEVIEW_ID").vector(),
+    character("SMOKEVIEW_PARAMETERS").vector(),
+    character("SPEC_ID"),
+    real("SPRAY_ANGLE").array(2).units("°"),
+    real("SPRAY_PATTERN_BETA"),
+    real("SPRAY_PATTERN_MU"),
+    character("SPRAY_PATTERN_SHAPE"),
+    character("SPRAY_PATTERN_TABLE"),
+    integer("VELOCITY_COMPONENT"),
+];
+
+const RADI: &[Parameter] = &[
+    integer("ANGLE_INCREMENT")
+        .default(Value::Integer(5))
+        .min(1.0),
+    real("BAND_LIMITS").vector(),
+    real("C_MAX"),
+    real("C_MIN"),
+    integer("INITIAL_RADIATION_ITERATIONS"),
+    real("KAPPA0"),
+    real("MIE_MAXIMUM_DIAMETER"),
+    real("MIE_MINIMUM_DIAMETER"),
+    integer("MIE_NDG"),
+    integer("NMIEANG"),
+    integer("NUMBER_INITIAL_ITERATIONS"),
+    integer("NUMBER_RADIATION_ANGLES")
+        .default(Value::Integer(100))
+        .min(1.0),
+    logical("OPTICALLY_THIN"),
+    real("PATH_LENGTH").units("m").min(0.0),
+    real("QR_CLIP"),
+    logical("RADIATION").default(Value::Logical(true)),
+    integer("RADIATION_ITERATIONS"),
+    real("RADIATIVE_FRACTION"),
+    real("RADTMP").default(Value::Real(900.0)).units("°C"),
+    logical("RTE_SOURCE_CORRECTION"),
+    integer("TIME_STEP_INCREMENT")
+        .default(Value::Integer(3))
+        .min(1.0),
+    logical("WIDE_BAND_MODEL").default(Value::Logical(false)),
+];
+
+const RAMP: &[Parameter] = &[
+    character("CTRL_ID"),
+    character("DEVC_ID"),
+    logical("EXTERNAL_FILE"),
+    real("F"),
+    character("FYI"),
+    character("ID"),
+    integer("NUMBER_INTERPOLATION_POINTS")
+        .default(Value::Integer(5000))
+        .min(1.0),
+    real("T").units("s"),
+    real("X").units("m"),
+    real("Z").units("m"),
+];
+
+const REAC: &[Parameter] = &[
+    real("A"),
+    real("AIT_EXCLUSION_ZONE").matrix().units("m"),
+    character("AIT_EXCLUSION_ZONE_CTRL_ID").vector(),
+    character("AIT_EXCLUSION_ZONE_DEVC_ID").vector(),
+    real("AIT_EXCLUSION_ZONE_TEMPERATURE").vector().units("°C"),
+    character("ALT_REAC_ID"),
+    real("AUTO_IGNITION_TEMPERATURE")
+        .default(Value::Real(-273.0))
+        .units("°C"),
+    real("C").min(0.0),
+    logical("CHECK_ATOM_BALANCE").default(Value::Logical(true)),
+    real("CO_YIELD")
+        .default(Value::Real(0.0))
+        .units("kg/kg")
+        .min(0.0)
+        .max(1.0),
+    real("CRITICAL_FLAME_TEMPERATURE")
+        .default(Value::Real(1427.0))
+        .units("°C"),
+    real("E"),
+    real("EPUMO2")
+        .default(Value::Real(13100.0))
+        .units("kJ/kg")
+        .min(0.0),
+    character("EQUATION"),
+    real("FIXED_MIX_TIME"),
+    real("FLAME_SPEED"),
+    real("FLAME_SPEED_EXPONENT"),
+    real("FLAME_SPEED_TEMPERATURE"),
+    character("FORMULA"),
+    character("FUEL"),
+    real("FUEL_C_TO_CO_FRACTION"),
+    real("FUEL_H_TO_H2_FRACTION"),
+    real("FUEL_N_TO_HCN_FRACTION"),
+    character("FUEL_RADCAL_ID"),
+    character("FWD_ID"),
+    character("FYI"),
+    real("H").min(0.0),
+    real("H2_YIELD"),
+    real("HCN_YIELD"),
+    real("HEAT_OF_COMBUSTION").units("kJ/kg").min(0.0),
+    logical("HOC_COMPLETE"),
+    character("ID"),
+    logical("IDEAL").default(Value::Logical(false)),
+    real("K"),
+    real("LOWER_OXYGEN_LIMIT"),
+    real("N").min(0.0),
+    real("NU").vector(),
+    real("N_S").vector(),
+    integer("N_SIMPLE_CHEMISTRY_REACTIONS"),
+    real("N_T"),
+    real("O").min(0.0),
+    character("ODE_SOLVER"),
+    integer("PRIORITY"),
+    real("RADIATIVE_FRACTION")
+        .default(Value::Real(0.35))
+        .min(0.0)
+        .max(1.0),
+    character("RAMP_CHI_R"),
+    character("RAMP_FS"),
+    real("REAC_ATOM_ERROR"),
+    real("REAC_MASS_ERROR"),
+    logical("REVERSE"),
+    real("SOOT_H_FRACTION")
+        .default(Value::Real(0.1))
+        .min(0.0)
+        .max(1.0),
+    real("SOOT_YIELD")
+        .default(Value::Real(0.0))
+        .units("kg/kg")
+        .min(0.0)
+        .max(1.0),
+    character("SPEC_ID_NU").vector(),
+    character("SPEC_ID_N_S").vector(),
+    character("TABLE_FS"),
+    real("TAU_CHEM"),
+    real("TAU_FLAME"),
+    logical("THIRD_BODY"),
+    real("THIRD_EFF").vector(),
+    character("THIRD_EFF_ID").vector(),
+    real("TURBULENT_FLAME_SPEED_ALPHA"),
+    real("TURBULENT_FLAME_SPEED_EXPONENT"),
+    real("Y_P_MIN_EDC"),
+];
+
+const SLCF: &[Parameter] = &[
+    real("AGL_SLICE").units("m"),
+    logical("CELL_CENTERED").default(Value::Logical(false)),
+    character("DB"),
+    logical("EVACUATION").until(6, 7),
+    logical("FIRE_LINE"),
+    character("FYI"),
+    character("ID"),
+    integer("IOR"),
+    character("LEVEL_SET_FIRE_LINE"),
+    real("MAXIMUM_VALUE"),
+    integer("MESH_NUMBER"),
+    real("MINIMUM_VALUE"),
+    character("PART_ID"),
+    real("PBX").units("m"),
+    real("PBY").units("m"),
+    real("PBZ").units("m"),
+    character("PROP_ID"),
+    character("QUANTITY"),
+    character("QUANTITY2"),
+    character("REAC_ID"),
+    character("SPEC_ID"),
+    logical("VECTOR").default(Value::Logical(false)),
+    integer("VELO_INDEX").default(Value::Integer(0)),
+    real("XB").array(6).units("m"),
+];
+
+const SPEC: &[Parameter] = &[
+    logical("AEROSOL").default(Value::Logical(false)),
+    character("ALIAS"),
+    logical("BACKGROUND").default(Value::Logical(false)),
+    real("CONDUCTIVITY").units("W/(m·K)").min(0.0),
+    real("CONDUCTIVITY_SOLID"),
+    logical("COPY_LUMPED"),
+    real("DENSITY_LIQUID").units("kg/m³").min(0.0),
+    real("DENSITY_SOLID").units("kg/m³").min(0.0),
+    real("DIFFUSIVITY").units("m²/s").min(0.0),
+    real("ENTHALPY_OF_FORMATION").units("kJ/mol"),
+    real("EPSILONKLJ"),
+    real("FIC_CONCENTRATION"),
+    real("FLD_LETHAL_DOSE"),
+    character("FORMULA"),
+    character("FYI"),
+    real("HEAT_OF_VAPORIZATION").units("kJ/kg"),
+    real("H_V_REFERENCE_TEMPERATURE"),
+    character("ID"),
+    logical("LUMPED_COMPONENT_ONLY"),
+    real("MASS_EXTINCTION_COEFFICIENT").units("m²/kg").min(0.0),
+    real("MASS_FRACTION").vector(),
+    real("MASS_FRACTION_0").min(0.0).max(1.0),
+    real("MAX_DIAMETER").units("m"),
+    real("MEAN_DIAMETER").units("m").min(0.0),
+    real("MELTING_TEMPERATURE").units("°C"),
+    real("MIN_DIAMETER").units("m"),
+    real("MW").units("g/mol").min(0.0),
+    integer("N_BINS"),
+    real("POLYNOMIAL_COEFF").matrix(),
+    real("POLYNOMIAL_TEMP").vector().units("K"),
+    logical("PRIMITIVE"),
+    real("PR_GAS"),
+    character("RADCAL_ID"),
+    character("RAMP_CP"),
+    character("RAMP_CP_L"),
+    character("RAMP_D"),
+    character("RAMP_G_F"),
+    character("RAMP_K"),
+    character("RAMP_MU"),
+    real("REFERENCE_ENTHALPY"),
+    real("REFERENCE_TEMPERATURE").units("°C"),
+    real("SIGMALJ"),
+    real("SPECIFIC_HEAT").units("kJ/(kg·K)").min(0.0),
+    real("SPECIFIC_HEAT_LIQUID").units("kJ/(kg·K)").min(0.0),
+    character("SPEC_ID").vector(),
+    real("THERMOPHORETIC_DIAMETER").units("m"),
+    real("VAPORIZATION_TEMPERATURE").units("°C"),
+    real("VISCOSITY").units("kg/(m·s)").min(0.0),
+    real("VOLUME_FRACTION").vector(),
+];
+
+const SURF: &[Parameter] = &[
+    logical("ADIABATIC").default(Value::Logical(false)),
+    real("AUTO_IGNITION_TEMPERATURE")
+        .default(Value::Real(-273.0))
+        .units("°C"),
+    character("BACKING").default(Value::Character("VOID")),
+    logical("BLOWING"),
+    logical("BURN_AWAY").default(Value::Logical(false)),
+    real("BURN_DURATION").units("s"),
+    real("CELL_SIZE").vector().units("m"),
+    real("CELL_SIZE_FACTOR").default(Value::Real(1.0)).min(0.0),
+    character("COLOR"),
+    real("CONVECTION_LENGTH_SCALE")
+        .default(Value::Real(1.0))
+        .units("m")
+        .min(0.0),
+    real("CONVECTIVE_HEAT_FLUX").units("kW/m²"),
+    logical("CONVERT_VOLUME_TO_MASS"),
+    real("C_FORCED_CONSTANT"),
+    real("C_FORCED_PR_EXP"),
+    real("C_FORCED_RE"),
+    real("C_FORCED_RE_EXP"),
+    real("C_HORIZONTAL").default(Value::Real(1.52)),
+    real("C_VERTICAL").default(Value::Real(1.31)),
+    logical("DEFAULT").default(Value::Logical(false)),
+    real("DELTA_TMP_MAX").units("°C"),
+    real("DRAG_COEFFICIENT"),
+    real("DT_INSERT")
+        .default(Value::Real(0.01))
+        .units("s")
+        .min(0.0),
+    real("EMISSIVITY")
+        .default(Value::Real(0.9))
+        .min(0.0)
+        .max(1.0),
+    real("EMISSIVITY_BACK").min(0.0).max(1.0),
+    logical("EVAC_DEFAULT").until(6, 7),
+    real("EXTERNAL_FLUX").units("kW/m²"),
+    character("EXTERNAL_FLUX_RAMP"),
+    real("EXTINCTION_TEMPERATURE")
+        .default(Value::Real(-273.0))
+        .units("°C"),
+    real("E_COEFFICIENT").units("m²/kg"),
+    real("FIRELINE_MLR_MAX").units("kg/(m²·s)"),
+    logical("FREE_SLIP").default(Value::Logical(false)),
+    character("FYI"),
+    character("GEOMETRY").default(Value::Character("CARTESIAN")),
+    real("HEAT_OF_VAPORIZATION").units("kJ/kg"),
+    real("HEAT_TRANSFER_COEFFICIENT").units("W/(m²·K)").min(0.0),
+    real("HEAT_TRANSFER_COEFFICIENT_BACK")
+        .units("W/(m²·K)")
+        .min(0.0),
+    real("HEAT_TRANSFER_COEFFICIENT_SIGMA"),
+    character("HEAT_TRANSFER_MODEL"),
+    logical("HORIZONTAL"),
+    real("HRRPUA").units("kW/m²"),
+    logical("HT3D").default(Value::Logical(false)),
+    character("ID"),
+    real("IGNITION_TEMPERATURE")
+        .default(Value::Real(5000.0))
+        .units("°C"),
+    real("INERT_Q_REF"),
+    character("INIT_IDS").vector(),
+    real("INIT_PER_AREA").units("1/m²"),
+    real("INNER_RADIUS").units("m").min(0.0),
+    real("INTERNAL_HEAT_SOURCE").vector().units("kW/m³"),
+    real("LAYER_DIVIDE"),
+    integer("LEAK_PATH").vector(),
+    integer("LEAK_PATH_ID").array(2),
+    real("LENGTH").units("m").min(0.0),
+    real("MASS_FLUX").vector().units("kg/(m²·s)"),
+    real("MASS_FLUX_TOTAL").units("kg/(m²·s)"),
+    real("MASS_FLUX_VAR"),
+    real("MASS_FRACTION").vector(),
+    real("MASS_TRANSFER_COEFFICIENT").units("m/s"),
+    character("MATL_ID").vector(),
+    real("MATL_MASS_FRACTION").vector().min(0.0).max(1.0),
+    real("MINIMUM_BURNOUT_TIME").units("s"),
+    real("MINIMUM_LAYER_THICKNESS")
+        .default(Value::Real(1.0e-6))
+        .units("m")
+        .min(0.0),
+    real("MLRPUA").units("kg/(m²·s)"),
+    real("NEAR_WALL_EDDY_VISCOSITY"),
+    character("NEAR_WALL_TURBULENCE_MODEL"),
+    real("NET_HEAT_FLUX").units("kW/m²"),
+    logical("NO_SLIP").default(Value::Logical(false)),
+    integer("NPPC").default(Value::Integer(1)).min(1.0),
+    integer("N_CELLS_MAX"),
+    integer("N_LAYER_CELLS_MAX")
+        .vector()
+        .default(Value::Integers(&[1000]))
+        .min(1.0),
+    real("PARTICLE_MASS_FLUX").units("kg/(m²·s)"),
+    real("PARTICLE_SURFACE_DENSITY").units("kg/m²"),
+    character("PART_ID"),
+    real("PLE").default(Value::Real(0.3)),
+    character("PROFILE"),
+    real("RADIUS").units("m").min(0.0),
+    character("RAMP_EF"),
+    character("RAMP_HEAT_TRANSFER_COEFFICIENT"),
+    character("RAMP_HEAT_TRANSFER_COEFFICIENT_BACK"),
+    character("RAMP_IHS").vector(),
+    character("RAMP_MF").vector(),
+    character("RAMP_PART"),
+    character("RAMP_Q"),
+    character("RAMP_T"),
+    character("RAMP_TMP_BACK"),
+    character("RAMP_TMP_GAS_BACK"),
+    character("RAMP_TMP_GAS_FRONT"),
+    character("RAMP_T_I"),
+    character("RAMP_V"),
+    character("RAMP_V_X"),
+    character("RAMP_V_Y"),
+    character("RAMP_V_Z"),
+    real("REFERENCE_HEAT_FLUX").vector().units("kW/m²"),
+    real("REFERENCE_HEAT_FLUX_TIME_INTERVAL").units("s"),
+    real("REFERENCE_THICKNESS").vector().units("m"),
+    real("REMESH_RATIO"),
+    integer("RGB")
+        .array(3)
+        .default(Value::Integers(&[255, 204, 102]))
+        .min(0.0)
+        .max(255.0),
+    real("ROUGHNESS")
+        .default(Value::Real(0.0))
+        .units("m")
+        .min(0.0),
+    character("SPEC_ID").vector(),
+    real("SPREAD_RATE").units("m/s"),
+    real("STRETCH_FACTOR").default(Value::Real(2.0)).min(1.0),
+    real("SURFACE_VOLUME_RATIO").units("1/m"),
+    real("TAU_EF").default(Value::Real(1.0)).units("s"),
+    real("TAU_EXTERNAL_FLUX"),
+    real("TAU_MF").default(Value::Real(1.0)).units("s"),
+    real("TAU_PART").default(Value::Real(1.0)).units("s"),
+    real("TAU_Q").default(Value::Real(1.0)).units("s"),
+    real("TAU_T").default(Value::Real(1.0)).units("s"),
+    real("TAU_V").default(Value::Real(1.0)).units("s"),
+    real("TEXTURE_HEIGHT").default(Value::Real(1.0)).units("m"),
+    character("TEXTURE_MAP"),
+    real("TEXTURE_WIDTH").default(Value::Real(1.0)).units("m"),
+    logical("TGA_ANALYSIS").default(Value::Logical(false)),
+    real("TGA_FINAL_TEMPERATURE")
+        .default(Value::Real(800.0))
+        .units("°C"),
+    real("TGA_HEATING_RATE")
+        .default(Value::Real(5.0))
+        .units("K/min"),
+    real("THICKNESS").vector().units("m").min(0.0),
+    real("TMP_BACK").default(Value::Real(20.0)).units("°C"),
+    real("TMP_FRONT").default(Value::Real(20.0)).units("°C"),
+    real("TMP_FRONT_INITIAL").units("°C"),
+    real("TMP_GAS_BACK").units("°C"),
+    real("TMP_GAS_FRONT").units("°C"),
+    real("TMP_INNER").vector().units("°C"),
+    real("TRANSPARENCY")
+        .default(Value::Real(1.0))
+        .min(0.0)
+        .max(1.0),
+    logical("VARIABLE_THICKNESS"),
+    logical("VEGETATION"),
+    logical("VEGETATION_ARRHENIUS_DEGRAD"),
+    real("VEGETATION_CDRAG"),
+    real("VEGETATION_CHAR_FRACTION"),
+    integer("VEGETATION_ELEMENT_DENSITY"),
+    real("VEGETATION_GROUND_TEMP"),
+    real("VEGETATION_HEIGHT"),
+    real("VEGETATION_INITIAL_TEMP"),
+    integer("VEGETATION_LAYERS"),
+    logical("VEGETATION_LINEAR_DEGRAD"),
+    real("VEGETATION_LOAD"),
+    real("VEGETATION_LSET_IGNITE_TIME"),
+    real("VEGETATION_MOISTURE"),
+    logical("VEGETATION_NO_BURN"),
+    real("VEGETATION_SVRATIO"),
+    logical("VEG_LEVEL_SET_SPREAD"),
+    real("VEG_LSET_BETA"),
+    real("VEG_LSET_ELLIPSE"),
+    real("VEG_LSET_ELLIPSE_HEAD"),
+    real("VEG_LSET_HT"),
+    real("VEG_LSET_QCON"),
+    real("VEG_LSET_ROS_BACK"),
+    real("VEG_LSET_ROS_FLANK"),
+    real("VEG_LSET_ROS_HEAD"),
+    real("VEG_LSET_SIGMA"),
+    logical("VEG_LSET_TAN2"),
+    real("VEG_LSET_WIND_EXP"),
+    real("VEL").units("m/s"),
+    real("VEL_BULK").units("m/s"),
+    real("VEL_GRAD"),
+    real("VEL_T").array(2).units("m/s"),
+    real("VOLUME_FLOW").units("m³/s"),
+    real("VOLUME_FLUX"),
+    real("WIDTH").units("m").min(0.0),
+    real("XYZ").array(3).units("m"),
+    real("Z0").default(Value::Real(10.0)).units("m"),
+    real("ZETA_FRONT"),
+];
+
+const TABL: &[Parameter] = &[
+    character("FYI"),
+    character("ID"),
+    real("TABLE_DATA").vector(),
+];
+
+const TIME: &[Parameter] = &[
+    real("DT").units("s").min(0.0),
+    real("DT_END_FILL").units("s"),
+    real("DT_END_MINIMUM").units("s"),
+    real("EVAC_DT_FLOWFIELD").units("s").until(6, 7),
+    real("EVAC_DT_STEADY_STATE").units("s").until(6, 7),
+    character("FYI"),
+    real("LIMITING_DT_RATIO")
+        .default(Value::Real(1.0e-4))
+        .min(0.0),
+    logical("LOCK_TIME_STEP").default(Value::Logical(false)),
+    logical("RESTRICT_TIME_STEP").default(Value::Logical(true)),
+    real("TIME_SHRINK_FACTOR")
+        .default(Value::Real(1.0))
+        .min(1.0),
+    real("TWFIN").units("s"),
+    real("T_BEGIN").default(Value::Real(0.0)).units("s"),
+    real("T_END").default(Value::Real(1.0)).units("s"),
+    real("T_END_GEOM").units("s"),
+    integer("WALL_INCREMENT")
+        .default(Value::Integer(2))
+        .min(1.0),
+    integer("WALL_INCREMENT_HT3D"),
+];
+
+const TRNX: &[Parameter] = &[
+    real("CC").units("m"),
+    character("FYI"),
+    integer("IDERIV").default(Value::Integer(-1)),
+    integer("MESH_NUMBER").default(Value::Integer(1)).min(1.0),
+    real("PC").units("m"),
+];
+
+const TRNY: &[Parameter] = &[
+    real("CC").units("m"),
+    character("FYI"),
+    integer("IDERIV").default(Value::Integer(-1)),
+    integer("MESH_NUMBER").default(Value::Integer(1)).min(1.0),
+    real("PC").units("m"),
+];
+
+const TRNZ: &[Parameter] = &[
+    real("CC").units("m"),
+    character("FYI"),
+    integer("IDERIV").default(Value::Integer(-1)),
+    integer("MESH_NUMBER").default(Value::Integer(1)).min(1.0),
+    real("PC").units("m"),
+];
+
+const VENT: &[Parameter] = &[
+    logical("AREA_ADJUST"),
+    character("COLOR"),
+    character("CTRL_ID"),
+    character("DB"),
+    character("DEVC_ID"),
+    real("DYNAMIC_PRESSURE")
+        .default(Value::Real(0.0))
+        .units("Pa"),
+    logical("EVACUATION").until(6, 7),
+    character("FYI"),
+    character("ID"),
+    integer("IOR").min(-3.0).max(3.0),
+    real("L_EDDY").units("m"),
+    real("L_EDDY_IJ").matrix(),
+    character("MB"),
+    character("MESH_ID"),
+    character("MULT_ID"),
+    integer("N_EDDY"),
+    logical("OUTLINE").default(Value::Logical(false)),
+    real("PBX").units("m"),
+    real("PBY").units("m"),
+    real("PBZ").units("m"),
+    character("PRESSURE_RAMP"),
+    real("RADIUS").units("m").min(0.0),
+    real("REYNOLDS_STRESS").matrix(),
+    integer("RGB").array(3).min(0.0).max(255.0),
+    real("SPREAD_RATE").units("m/s"),
+    character("SURF_ID"),
+    real("TEXTURE_ORIGIN").array(3).units("m"),
+    real("TMP_EXTERIOR").units("°C"),
+    character("TMP_EXTERIOR_RAMP"),
+    real("TRANSPARENCY")
+        .default(Value::Real(1.0))
+        .min(0.0)
+        .max(1.0),
+    real("UVW").array(3).units("m/s"),
+    real("VEL_RMS").units("m/s").min(0.0),
+    logical("WIND"),
+    real("XB").array(6).units("m"),
+    real("XYZ").array(3).units("m"),
+];
+
+const ZONE: &[Parameter] = &[
+    real("DISCHARGE_COEFFICIENT").vector(),
+    character("ID"),
+    real("LEAK_AREA").vector().units("m²").min(0.0),
+    real("LEAK_PRESSURE_EXPONENT").default(Value::Real(0.5)),
+    real("LEAK_REFERENCE_PRESSURE")
+        .default(Value::Real(4.0))
+        .units("Pa"),
+    logical("PERIODIC").default(Value::Logical(false)),
+    real("XB").array(6).units("m"),
+    real("XYZ").matrix().units("m"),
+];
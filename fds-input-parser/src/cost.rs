@@ -0,0 +1,193 @@
+//! Rough estimates of the computational cost of running an [`FDSFile`], for
+//! sizing a job before it is submitted.
+use crate::decode::*;
+
+/// The assumptions used to estimate the cost of a simulation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CostParameters {
+    /// The CFL number at which FDS is expected to run. FDS keeps the CFL number
+    /// between 0.8 and 1.0 by default.
+    pub cfl: f64,
+    /// The characteristic maximum flow speed (m/s), which with the cell size
+    /// limits the time step.
+    pub velocity: f64,
+    /// The memory used per cell (bytes). FDS uses about 1 GB per million cells
+    /// for simple cases, more with many species or particles.
+    pub bytes_per_cell: f64,
+}
+
+impl Default for CostParameters {
+    fn default() -> Self {
+        CostParameters {
+            cfl: 1.0,
+            velocity: 5.0,
+            bytes_per_cell: 1000.0,
+        }
+    }
+}
+
+/// The share of a simulation run by one MPI process.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ProcessCost {
+    /// The MPI process number (0-based, as in MPI_PROCESS).
+    pub mpi_process: i64,
+    /// The indices in [`FDSFile::meshes`] of the meshes on this process.
+    pub meshes: Vec<usize>,
+    pub n_cells: u64,
+    /// The estimated memory use (bytes).
+    pub memory: f64,
+}
+
+/// A reason the MPI process of each mesh cannot be determined.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MpiProcessError {
+    /// Some meshes set MPI_PROCESS and others do not. The meshes are given by
+    /// their indices in [`FDSFile::meshes`].
+    Mixed {
+        with: Vec<usize>,
+        without: Vec<usize>,
+    },
+    /// A mesh, given by its index in [`FDSFile::meshes`], has a negative
+    /// MPI_PROCESS.
+    Negative { mesh: usize, mpi_process: i64 },
+}
+
+impl std::fmt::Display for MpiProcessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mixed { with, without } => write!(
+                f,
+                "MPI_PROCESS is set on {} meshes but not on {} others",
+                with.len(),
+                without.len()
+            ),
+            Self::Negative { mesh, mpi_process } => write!(
+                f,
+                "MESH #{} has a negative MPI_PROCESS ({})",
+                mesh + 1,
+                mpi_process
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MpiProcessError {}
+
+/// An estimate of the cost of a simulation.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CostEstimate {
+    pub n_cells: u64,
+    pub processes: Vec<ProcessCost>,
+    /// The time step (s) allowed by the CFL limit. All meshes advance at the
+    /// same time step, so this is the smallest over all meshes.
+    pub time_step: f64,
+    /// The number of time steps from T_BEGIN to T_END.
+    pub n_time_steps: u64,
+}
+
+impl CostEstimate {
+    /// The total estimated memory use (bytes).
+    pub fn memory(&self) -> f64 {
+        self.processes.iter().map(|process| process.memory).sum()
+    }
+
+    /// The estimated wall-clock time (s) given the throughput of a single
+    /// process in cell-updates per second (cells·steps/s). The processes run in
+    /// parallel, so the time is that of the process with the most cells.
+    pub fn wall_clock(&self, throughput: f64) -> f64 {
+        let max_cells = self
+            .processes
+            .iter()
+            .map(|process| process.n_cells)
+            .max()
+            .unwrap_or(0);
+        (max_cells as f64) * (self.n_time_steps as f64) / throughput
+    }
+}
+
+impl FDSFile {
+    /// The MPI process each mesh will run on. If no mesh sets MPI_PROCESS,
+    /// each mesh is given a process of its own, as FDS does. MPI_PROCESS must
+    /// be set on either all of the meshes or none of them, and must not be
+    /// negative.
+    pub fn mpi_processes(&self) -> Result<Vec<i64>, MpiProcessError> {
+        let (with, without): (Vec<usize>, Vec<usize>) =
+            (0..self.meshes.len()).partition(|&i| self.meshes[i].mpi_process.is_some());
+        if with.is_empty() {
+            return Ok((0..self.meshes.len() as i64).collect());
+        }
+        if !without.is_empty() {
+            return Err(MpiProcessError::Mixed { with, without });
+        }
+        let processes: Vec<i64> = self
+            .meshes
+            .iter()
+            .filter_map(|mesh| mesh.mpi_process)
+            .collect();
+        match processes.iter().position(|&process| process < 0) {
+            Some(mesh) => Err(MpiProcessError::Negative {
+                mesh,
+                mpi_process: processes[mesh],
+            }),
+            None => Ok(processes),
+        }
+    }
+
+    /// Estimate the cells, memory, and time steps needed to run the simulation.
+    pub fn estimate_cost(
+        &self,
+        parameters: &CostParameters,
+    ) -> Result<CostEstimate, MpiProcessError> {
+        let mut processes: Vec<ProcessCost> = Vec::new();
+        for (i, (mesh, mpi_process)) in self.meshes.iter().zip(self.mpi_processes()?).enumerate() {
+            let n_cells = mesh.n_cells();
+            let memory = (n_cells as f64) * parameters.bytes_per_cell;
+            match processes
+                .iter_mut()
+                .find(|process| process.mpi_process == mpi_process)
+            {
+                Some(process) => {
+                    process.meshes.push(i);
+                    process.n_cells += n_cells;
+                    process.memory += memory;
+                }
+                None => processes.push(ProcessCost {
+                    mpi_process,
+                    meshes: vec![i],
+                    n_cells,
+                    memory,
+                }),
+            }
+        }
+        processes.sort_by_key(|process| process.mpi_process);
+        let time_step = self
+            .meshes
+            .iter()
+            .map(|mesh| parameters.cfl * mesh.resolution().max_side() / parameters.velocity)
+            .fold(f64::INFINITY, f64::min);
+        let duration = self
+            .time
+            .as_ref()
+            .map(|time| time.duration())
+            .unwrap_or_else(|| Time::default().duration());
+        let n_time_steps = if time_step.is_finite() && time_step > 0.0 {
+            (duration / time_step).ceil() as u64
+        } else {
+            0
+        };
+        Ok(CostEstimate {
+            n_cells: self.meshes.iter().map(|mesh| mesh.n_cells()).sum(),
+            processes,
+            time_step,
+            n_time_steps,
+        })
+    }
+}
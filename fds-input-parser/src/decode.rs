@@ -1,3 +1,4 @@
+use crate::catalog;
 use crate::xb::{HasXB, MightHaveXB};
 ///! This module defines data structures for FDS input data, and the functions
 ///! for converting them to and from Fortran Namelists. This is inherently less
@@ -23,6 +24,8 @@ use std::convert::{TryFrom, TryInto};
 /// provision for storing namelists that are not understood for the purposes of
 /// forward compatibility.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct FDSFile {
     pub head: Option<Head>,
     pub time: Option<Time>,
@@ -30,6 +33,7 @@ pub struct FDSFile {
     pub misc: Option<Misc>,
     pub meshes: Vec<Mesh>,
     pub reacs: Vec<Reac>,
+    pub ctrls: Vec<Ctrl>,
     pub devcs: Vec<Devc>,
     pub matls: Vec<Matl>,
     pub surfs: Vec<Surf>,
@@ -43,12 +47,86 @@ pub struct FDSFile {
     pub ramps: Vec<Ramp>,
     pub props: Vec<Prop>,
     pub parts: Vec<Part>,
+    pub specs: Vec<Spec>,
+    pub mults: Vec<Mult>,
     pub trnxs: Vec<Trnx>,
     pub trnys: Vec<Trny>,
     pub trnzs: Vec<Trnz>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::json::namelists"))]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(with = "Vec<crate::json::UnknownNamelist>")
+    )]
     pub unknown_namelists: Vec<Namelist>,
+    /// Parameters which are not valid for their namelist group, and were
+    /// ignored when decoding.
+    pub unknown_parameters: Vec<UnknownParameter>,
+    /// Namelists which could not be decoded. Each is also kept in
+    /// `unknown_namelists`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub decode_errors: Vec<DecodeError>,
+    /// The number of SURFs at the start of `surfs` which FDS defines itself
+    /// (see [`BUILTIN_SURF_IDS`]) rather than the input.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub builtin_surfs: usize,
 }
 
+/// A parameter which the [`catalog`] does not list for its namelist group.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UnknownParameter {
+    /// The namelist group, such as `"SURF"`.
+    pub namelist: String,
+    /// The ID of the namelist the parameter was given in, if it has one.
+    pub id: Option<String>,
+    pub parameter: String,
+}
+
+/// A namelist which could not be decoded.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DecodeError {
+    /// The namelist group, such as `"TRNX"`.
+    pub namelist: String,
+    /// The ID of the namelist, if it has one.
+    pub id: Option<String>,
+    pub kind: DecodeErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DecodeErrorKind {
+    /// A parameter which FDS requires was not given.
+    MissingParameter(String),
+    /// A parameter was not of the type FDS expects.
+    InvalidParameter(String),
+    /// The namelist group may only be given once.
+    Repeated,
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.namelist)?;
+        if let Some(id) = &self.id {
+            write!(f, " '{}'", id)?;
+        }
+        match &self.kind {
+            DecodeErrorKind::MissingParameter(parameter) => {
+                write!(f, ": {} is required", parameter)
+            }
+            DecodeErrorKind::InvalidParameter(parameter) => {
+                write!(f, ": {} has an invalid value", parameter)
+            }
+            DecodeErrorKind::Repeated => write!(f, ": may only be given once"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
 impl FDSFile {
     pub fn new() -> Self {
         Default::default()
@@ -70,6 +148,33 @@ impl FDSFile {
     pub fn decode_namelist(&mut self, namelist: &Namelist) {
         decode_namelist(self, namelist)
     }
+
+    /// The TRNX transformations which apply to the mesh with the given
+    /// (1-based) mesh number, as used by MESH_NUMBER.
+    pub fn trnxs_for_mesh(&self, mesh_number: i64) -> Vec<&Trnx> {
+        self.trnxs
+            .iter()
+            .filter(|trn| trn.mesh_number == mesh_number)
+            .collect()
+    }
+
+    /// The TRNY transformations which apply to the mesh with the given
+    /// (1-based) mesh number, as used by MESH_NUMBER.
+    pub fn trnys_for_mesh(&self, mesh_number: i64) -> Vec<&Trny> {
+        self.trnys
+            .iter()
+            .filter(|trn| trn.mesh_number == mesh_number)
+            .collect()
+    }
+
+    /// The TRNZ transformations which apply to the mesh with the given
+    /// (1-based) mesh number, as used by MESH_NUMBER.
+    pub fn trnzs_for_mesh(&self, mesh_number: i64) -> Vec<&Trnz> {
+        self.trnzs
+            .iter()
+            .filter(|trn| trn.mesh_number == mesh_number)
+            .collect()
+    }
 }
 
 impl Default for FDSFile {
@@ -81,6 +186,7 @@ impl Default for FDSFile {
             misc: None,
             meshes: vec![],
             reacs: vec![],
+            ctrls: vec![],
             devcs: vec![],
             matls: vec![],
             surfs: vec![],
@@ -94,15 +200,22 @@ impl Default for FDSFile {
             ramps: vec![],
             props: vec![],
             parts: vec![],
+            specs: vec![],
+            mults: vec![],
             trnxs: vec![],
             trnys: vec![],
             trnzs: vec![],
             unknown_namelists: vec![],
+            unknown_parameters: vec![],
+            decode_errors: vec![],
+            builtin_surfs: 0,
         }
     }
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Head {
     pub chid: Option<String>,
     pub fyi: Option<String>,
@@ -110,6 +223,8 @@ pub struct Head {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Bndf {
     cell_centred: bool,
     fyi: Option<String>,
@@ -122,6 +237,8 @@ pub struct Bndf {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Bnde {
     cell_centered: bool,
     fyi: Option<String>,
@@ -132,6 +249,8 @@ pub struct Bnde {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Clip {
     fyi: Option<String>,
     maximum_density: f64,
@@ -143,44 +262,50 @@ pub struct Clip {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Ctrl {
-    constant: f64,
+    pub constant: Option<f64>,
     // , CYCLES : String
     // , CYCLE_TIME : String
-    delay: f64,
-    differential_gain: f64,
-    evacuation: bool,
-    function_type: String,
-    id: String,
-    initial_state: bool,
-    integral_gain: f64,
-    input_id: Vec<String>,
-    latch: bool,
-    n: i64,
-    on_bound: String,
-    proportional_gain: f64,
-    ramp_id: String,
-    setpoint: f64,
-    target_value: f64,
-    trip_direction: i64,
+    pub delay: f64,
+    pub differential_gain: f64,
+    pub evacuation: Option<bool>,
+    pub function_type: Option<String>,
+    pub id: Option<String>,
+    pub initial_state: bool,
+    pub integral_gain: f64,
+    pub input_id: Vec<String>,
+    pub latch: bool,
+    pub n: i64,
+    pub on_bound: String,
+    pub proportional_gain: f64,
+    pub ramp_id: Option<String>,
+    pub setpoint: Option<f64>,
+    pub target_value: f64,
+    pub trip_direction: i64,
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Csvf {
     csvfile: String,
     uvwfile: String,
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Devc {
     // bypass_flowrate: f64,
     // conversion_addend: f64,
     // conversion_factor: f64,
     // coord_factor: f64,
-    // ctrl_id: Option<String>,
+    pub ctrl_id: Option<String>,
     // delay: f64,
     // depth: f64,
-    // devc_id: Option<String>,
+    pub devc_id: Option<String>,
     // dry: bool,
     // duct_id: Option<String>,
     // evacuation: bool,
@@ -192,18 +317,18 @@ pub struct Devc {
     // init_id: Option<String>,
     // ior: Option<i64>,
     // latch: bool,
-    // matl_id: Option<String>,
+    pub matl_id: Option<String>,
     // node_id: Vec<String>,
     // no_update_devc_id: Option<String>,
     // no_update_ctrl_id: Option<String>,
     // orientation: XYZ,
     // orientation_number: i64,
     // output: bool,
-    // part_id: Option<String>,
+    pub part_id: Option<String>,
     // pipe_index: i64,
     // points: i64,
     pub prop_id: Option<String>,
-    // quantity: Option<String>,
+    pub quantity: Option<String>,
     // quantity2: Option<String>,
     // quantity_range: (f64, f64),
     // r_id: Option<String>,
@@ -212,7 +337,7 @@ pub struct Devc {
     // rotation: f64,
     // setpoint: Option<f64>,
     // smoothing_factor: f64,
-    // spec_id: Option<String>,
+    pub spec_id: Option<String>,
     // statistics: Option<String>,
     // statistics_start: f64,
     // surf_id: Option<String>,
@@ -222,6 +347,7 @@ pub struct Devc {
     // units: Option<String>,
     // velo_index: i64,
     // xb: Option<XB>,
+    /// The location of the device.
     pub xyz: Option<XYZ>,
     // x_id: Option<String>,
     // y_id: Option<String>,
@@ -235,18 +361,25 @@ impl Devc {
             Some(x) => x,
             None => return false,
         };
-        println!("devc has prop {:?}", self.prop_id);
         // Get the prop.
         for prop in &fds_file.props {
             if prop.id.as_ref() == Some(prop_id) {
                 return prop.is_sprinkler_prop();
             }
         }
+        log::warn!(
+            namelist = "DEVC",
+            id:? = self.id,
+            prop_id = prop_id.as_str();
+            "PROP_ID refers to an undefined PROP"
+        );
         false
     }
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Dump {
     clip_restart_files: bool,
     column_dump_limit: bool,
@@ -294,21 +427,32 @@ pub struct Dump {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Hole {
-    color: String,
-    ctrl_id: String,
-    devc_id: String,
-    evacuation: bool,
-    fyi: Option<String>,
-    id: String,
-    mesh_id: String,
-    mult_id: String,
-    rgb: RGB,
-    transparency: f64,
-    xb: XB,
+    pub color: Option<String>,
+    pub ctrl_id: Option<String>,
+    pub devc_id: Option<String>,
+    pub evacuation: Option<bool>,
+    pub fyi: Option<String>,
+    pub id: Option<String>,
+    pub mesh_id: Option<String>,
+    pub mult_id: Option<String>,
+    pub rgb: Option<RGB>,
+    pub transparency: f64,
+    /// The region removed from the obstructions it intersects.
+    pub xb: XB,
+}
+
+impl HasXB for Hole {
+    fn xb(&self) -> XB {
+        self.xb
+    }
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Hvac {
     aircoil_id: Option<String>,
     ambient: bool,
@@ -355,6 +499,8 @@ pub struct Hvac {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Init {
     auto_ignition_temperature: f64,
     cell_centered: bool,
@@ -388,6 +534,8 @@ pub struct Init {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Isof {
     fyi: Option<String>,
     quantity: String,
@@ -397,42 +545,44 @@ pub struct Isof {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Matl {
-    a: Vec<f64>,
-    absorption_coefficient: f64,
-    boiling_temperature: f64,
-    color: String,
-    conductivity: f64,
-    conductivity_ramp: String,
-    density: f64,
-    e: Vec<f64>,
-    emissivity: f64,
-    fyi: Option<String>,
-    heating_rate: Vec<f64>,
-    heat_of_combustion: Vec<f64>,
-    heat_of_reaction: Vec<f64>,
-    id: String,
-    matl_id: Vec<String>,
-    nu_matl: Vec<f64>,
-    nu_spec: Vec<f64>,
-    n_reactions: i64,
-    n_s: Vec<f64>,
-    n_t: Vec<f64>,
-    n_o2: Vec<f64>,
-    pcr: Vec<bool>,
-    pyrolysis_range: Vec<f64>,
-    reference_rate: Vec<f64>,
-    reference_temperature: Vec<f64>,
-    rgb: RGB,
-    specific_heat: f64,
-    specific_heat_ramp: String,
-    spec_id: Vec<String>,
-    threshold_sign: Vec<f64>,
-    threshold_temperature: Vec<f64>,
+    pub a: Vec<f64>,
+    pub absorption_coefficient: f64,
+    pub boiling_temperature: Option<f64>,
+    pub color: Option<String>,
+    pub conductivity: Option<f64>,
+    pub conductivity_ramp: Option<String>,
+    pub density: Option<f64>,
+    pub e: Vec<f64>,
+    pub emissivity: f64,
+    pub fyi: Option<String>,
+    pub heating_rate: Vec<f64>,
+    pub heat_of_combustion: Vec<f64>,
+    pub heat_of_reaction: Vec<f64>,
+    pub id: Option<String>,
+    pub matl_id: Vec<String>,
+    pub nu_matl: Vec<f64>,
+    pub nu_spec: Vec<f64>,
+    pub n_reactions: Option<i64>,
+    pub n_s: Vec<f64>,
+    pub n_t: Vec<f64>,
+    pub n_o2: Vec<f64>,
+    pub pcr: Vec<bool>,
+    pub pyrolysis_range: Vec<f64>,
+    pub reference_rate: Vec<f64>,
+    pub reference_temperature: Vec<f64>,
+    pub rgb: Option<RGB>,
+    pub specific_heat: Option<f64>,
+    pub specific_heat_ramp: Option<String>,
+    pub spec_id: Vec<String>,
+    pub threshold_sign: Vec<f64>,
+    pub threshold_temperature: Vec<f64>,
     // , POROSITY : String
-    allow_shrinking: bool,
-    allow_swelling: bool,
-    gas_diffusion_depth: Vec<f64>,
+    pub allow_shrinking: Option<bool>,
+    pub allow_swelling: Option<bool>,
+    pub gas_diffusion_depth: Vec<f64>,
 }
 
 // data PyrolysisReac
@@ -449,6 +599,8 @@ pub struct Matl {
 //         }
 //     | NoPyrolysis
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Resolution {
     pub x: f64,
     pub y: f64,
@@ -469,25 +621,40 @@ impl Resolution {
         }
         max
     }
+    /// The cell size along an axis.
+    pub fn along(&self, axis: Plane) -> f64 {
+        match axis {
+            Plane::X => self.x,
+            Plane::Y => self.y,
+            Plane::Z => self.z,
+        }
+    }
 }
 
 // ----------------------------------------
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Mesh {
     pub id: Option<String>,
+    /// The extent of the mesh.
     pub xb: XB,
+    /// The number of cells along each axis.
     pub ijk: IJK,
-    // pub color: String,
-    // pub cylindrical: bool,
+    pub check_mesh_alignment: bool,
+    pub color: Option<String>,
+    pub cylindrical: bool,
     // pub evacuation: bool,
     // pub evac_humans: bool,
     // pub evac_z_offset: f64,
-    // pub fyi: Option<String>,
-    // pub level: i64,
-    // pub mpi_process: Option<i64>,
-    // pub mult_id: Option<String>,
-    // pub rgb: RGB,
-    // pub n_threads: Option<i64>, // , PERIODIC_MESH_IDS : [Text]
+    pub fyi: Option<String>,
+    pub level: i64,
+    /// The MPI process which computes the mesh, counted from 0.
+    pub mpi_process: Option<i64>,
+    pub mult_id: Option<String>,
+    pub rgb: Option<RGB>,
+    pub n_threads: Option<i64>, // , PERIODIC_MESH_IDS : [Text]
 }
 
 impl Mesh {
@@ -512,6 +679,32 @@ impl Mesh {
     }
 }
 
+impl Default for Mesh {
+    /// The FDS defaults for a MESH with no parameters specified.
+    fn default() -> Self {
+        let xb = catalog::default_reals("MESH", "XB");
+        let ijk = catalog::default_integers("MESH", "IJK");
+        Mesh {
+            id: None,
+            xb: XB::new(xb[0], xb[1], xb[2], xb[3], xb[4], xb[5]),
+            ijk: IJK {
+                i: ijk[0],
+                j: ijk[1],
+                k: ijk[2],
+            },
+            check_mesh_alignment: catalog::default_logical("MESH", "CHECK_MESH_ALIGNMENT"),
+            color: None,
+            cylindrical: catalog::default_logical("MESH", "CYLINDRICAL"),
+            fyi: None,
+            level: catalog::default_integer("MESH", "LEVEL"),
+            mpi_process: None,
+            mult_id: None,
+            rgb: None,
+            n_threads: None,
+        }
+    }
+}
+
 impl HasXB for Mesh {
     fn xb(&self) -> XB {
         self.xb.clone()
@@ -531,6 +724,9 @@ impl HasXB for &mut Mesh {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Misc {
     // agglomeration: bool,
     // aerosol_al2o3: bool,
@@ -599,6 +795,7 @@ pub struct Misc {
     // les_filter_width: String,
     // max_chemistry_iterations: i64,
     // max_leak_paths: i64,
+    /// The upper limit of the visibility range in Smokeview.
     pub maximum_visibility: f64,
     // mean_forcing: Vec<bool>,
     // mpi_timeout: f64,
@@ -622,7 +819,7 @@ pub struct Misc {
     // pr: f64,
     // process_all_meshes: bool,
     // projection: bool,
-    // p_inf: f64,
+    pub p_inf: f64,
     // // , RAMP_FVX_T : String
     // // , RAMP_FVY_T : String
     // // , RAMP_FVZ_T : String
@@ -666,7 +863,7 @@ pub struct Misc {
     // thicken_obstructions: bool,
     // // , TRANSPORT_UNMIXED_FRACTION : String
     // // , TRANSPORT_ZETA_SCHEME : String
-    // tmpa: f64,
+    pub tmpa: f64,
     // turbulence_model: String,
     // turbulent_deposition: bool,
     // // , TURB_INIT_CLOCK : String
@@ -687,60 +884,119 @@ pub struct Misc {
     // // , WIND_ONLY : String
 }
 
+impl Default for Misc {
+    /// The FDS defaults, which also apply when there is no MISC namelist.
+    fn default() -> Self {
+        Misc {
+            maximum_visibility: catalog::default_real("MISC", "MAXIMUM_VISIBILITY"),
+            p_inf: catalog::default_real("MISC", "P_INF"),
+            tmpa: catalog::default_real("MISC", "TMPA"),
+            visibility_factor: catalog::default_real("MISC", "VISIBILITY_FACTOR"),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Mult {
-    dx: f64,
-    dxb: Vec<f64>,
-    dx0: f64,
-    dy: f64,
-    dyb: Vec<f64>,
-    dy0: f64,
-    dz: f64,
-    dzb: Vec<f64>,
-    dz0: f64,
-    id: String,
-    i_lower: i64,
-    i_upper: i64,
-    j_lower: i64,
-    j_upper: i64,
-    k_lower: i64,
-    k_upper: i64,
-    n_lower: i64,
-    n_upper: i64,
+    /// The offset along x between copies.
+    pub dx: f64,
+    pub dxb: Vec<f64>,
+    /// The offset along x of the first copy.
+    pub dx0: f64,
+    /// The offset along y between copies.
+    pub dy: f64,
+    pub dyb: Vec<f64>,
+    /// The offset along y of the first copy.
+    pub dy0: f64,
+    /// The offset along z between copies.
+    pub dz: f64,
+    pub dzb: Vec<f64>,
+    /// The offset along z of the first copy.
+    pub dz0: f64,
+    pub id: Option<String>,
+    pub i_lower: i64,
+    pub i_upper: i64,
+    pub j_lower: i64,
+    pub j_upper: i64,
+    pub k_lower: i64,
+    pub k_upper: i64,
+    pub n_lower: i64,
+    pub n_upper: i64,
+}
+
+impl Default for Mult {
+    fn default() -> Self {
+        Mult {
+            dx: catalog::default_real("MULT", "DX"),
+            dxb: vec![],
+            dx0: catalog::default_real("MULT", "DX0"),
+            dy: catalog::default_real("MULT", "DY"),
+            dyb: vec![],
+            dy0: catalog::default_real("MULT", "DY0"),
+            dz: catalog::default_real("MULT", "DZ"),
+            dzb: vec![],
+            dz0: catalog::default_real("MULT", "DZ0"),
+            id: None,
+            i_lower: catalog::default_integer("MULT", "I_LOWER"),
+            i_upper: catalog::default_integer("MULT", "I_UPPER"),
+            j_lower: catalog::default_integer("MULT", "J_LOWER"),
+            j_upper: catalog::default_integer("MULT", "J_UPPER"),
+            k_lower: catalog::default_integer("MULT", "K_LOWER"),
+            k_upper: catalog::default_integer("MULT", "K_UPPER"),
+            n_lower: catalog::default_integer("MULT", "N_LOWER"),
+            n_upper: catalog::default_integer("MULT", "N_UPPER"),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Obst {
     // allow_vent: bool,
     // bndf_face: (bool, bool, bool, bool, bool, bool),
     // bndf_obst: bool,
     // bulk_density: Option<f64>,
     // color: Option<String>,
-    // ctrl_id: Option<String>,
-    // devc_id: Option<String>,
+    pub ctrl_id: Option<String>,
+    pub devc_id: Option<String>,
     // evacuation: bool,
     // fyi: Option<String>,
     // ht3d: bool,
     pub id: Option<String>,
-    // matl_id: Option<String>,
-    // mesh_id: Option<String>,
-    // mult_id: Option<String>,
+    pub matl_id: Option<String>,
+    pub mesh_id: Option<String>,
+    pub mult_id: Option<String>,
     // // , NOTERRAIN : bool
     // outline: bool,
     // overlay: bool,
-    // permit_hole: bool,
-    // prop_id: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default = "default_obst_permit_hole"))]
+    pub permit_hole: bool,
+    pub prop_id: Option<String>,
     // removable: bool,
     // rgb: Option<RGB>,
     pub surf_id: Option<String>,
     pub surf_id6: Option<(String, String, String, String, String, String)>,
     pub surf_ids: Option<(String, String, String)>,
     // texture_origin: XYZ,
-    // thicken: bool,
+    #[cfg_attr(feature = "serde", serde(default = "default_obst_thicken"))]
+    pub thicken: bool,
     // transparency: f64,
+    /// The extent of the obstruction.
     pub xb: XB,
 }
 
+fn default_obst_permit_hole() -> bool {
+    catalog::default_logical("OBST", "PERMIT_HOLE")
+}
+
+fn default_obst_thicken() -> bool {
+    catalog::default_logical("OBST", "THICKEN")
+}
+
 impl Obst {
     pub fn surf_ids(&self) -> Vec<String> {
         let mut ss = Vec::with_capacity(6);
@@ -772,13 +1028,56 @@ impl Obst {
         ss
     }
 
+    /// Test if a SURF is applied to any face of the obstruction.
     pub fn has_surf(&self, surf_id: &str) -> bool {
-        for id in self.surf_ids() {
-            if &id == surf_id {
-                return true;
-            }
+        Direction::ALL
+            .iter()
+            .any(|&direction| self.surf_for_face(direction) == surf_id)
+    }
+
+    /// The SURF applied to a face of the obstruction. As in FDS, SURF_ID6 takes
+    /// precedence over SURF_IDS (top, sides, bottom), which takes precedence
+    /// over SURF_ID, and faces with none of these are INERT.
+    pub fn surf_for_face(&self, direction: Direction) -> &str {
+        if let Some((neg_x, pos_x, neg_y, pos_y, neg_z, pos_z)) = &self.surf_id6 {
+            return match direction {
+                Direction::NegX => neg_x,
+                Direction::PosX => pos_x,
+                Direction::NegY => neg_y,
+                Direction::PosY => pos_y,
+                Direction::NegZ => neg_z,
+                Direction::PosZ => pos_z,
+            };
         }
-        false
+        if let Some((top, sides, bottom)) = &self.surf_ids {
+            return match direction {
+                Direction::PosZ => top,
+                Direction::NegZ => bottom,
+                _ => sides,
+            };
+        }
+        match &self.surf_id {
+            Some(surf_id) => surf_id.as_str(),
+            None => "INERT",
+        }
+    }
+
+    /// The area of one face of the obstruction.
+    pub fn face_area(&self, direction: Direction) -> f64 {
+        match direction.axis() {
+            Plane::X => (self.xb.y2 - self.xb.y1) * (self.xb.z2 - self.xb.z1),
+            Plane::Y => (self.xb.x2 - self.xb.x1) * (self.xb.z2 - self.xb.z1),
+            Plane::Z => (self.xb.x2 - self.xb.x1) * (self.xb.y2 - self.xb.y1),
+        }
+    }
+
+    /// The total area of the faces to which a SURF is applied.
+    pub fn surf_area(&self, surf_id: &str) -> f64 {
+        Direction::ALL
+            .iter()
+            .filter(|&&direction| self.surf_for_face(direction) == surf_id)
+            .map(|&direction| self.face_area(direction))
+            .sum()
     }
 
     pub fn area_x(&self) -> Option<f64> {
@@ -796,67 +1095,28 @@ impl Obst {
         Some((xb.x2 - xb.x1) * (xb.y2 - xb.y1))
     }
 
+    /// The SURFs which are applied to at least one face of the obstruction.
+    /// Faces referring to an undefined SURF are skipped.
+    fn face_surfs<'a>(&'a self, fds_data: &'a FDSFile) -> impl Iterator<Item = &'a Surf> + 'a {
+        Direction::ALL.iter().filter_map(move |&direction| {
+            let surf_id = self.surf_for_face(direction);
+            fds_data
+                .surfs
+                .iter()
+                .find(|surf| surf.id.as_deref() == Some(surf_id))
+        })
+    }
+
     pub fn is_burner(&self, fds_data: &FDSFile) -> bool {
-        let surf_ids = self.surf_ids();
-        let surfaces: Vec<Option<&Surf>> = surf_ids
-            .iter()
-            .map(|surf_id| {
-                fds_data
-                    .surfs
-                    .iter()
-                    .find(|surf| surf.id.as_ref() == Some(surf_id))
-            })
-            .collect();
-        for surf in surfaces {
-            if let Some(surf) = surf {
-                if surf.is_burner() {
-                    return true;
-                }
-            }
-        }
-        false
+        self.face_surfs(fds_data).any(|surf| surf.is_burner())
     }
 
     pub fn is_extract(&self, fds_data: &FDSFile) -> bool {
-        let surf_ids = self.surf_ids();
-        let surfaces: Vec<Option<&Surf>> = surf_ids
-            .iter()
-            .map(|surf_id| {
-                fds_data
-                    .surfs
-                    .iter()
-                    .find(|surf| surf.id.as_ref() == Some(surf_id))
-            })
-            .collect();
-        for surf in surfaces {
-            if let Some(surf) = surf {
-                if surf.is_extract() {
-                    return true;
-                }
-            }
-        }
-        false
+        self.face_surfs(fds_data).any(|surf| surf.is_extract())
     }
 
     pub fn is_supply(&self, fds_data: &FDSFile) -> bool {
-        let surf_ids = self.surf_ids();
-        let surfaces: Vec<Option<&Surf>> = surf_ids
-            .iter()
-            .map(|surf_id| {
-                fds_data
-                    .surfs
-                    .iter()
-                    .find(|surf| surf.id.as_ref() == Some(surf_id))
-            })
-            .collect();
-        for surf in surfaces {
-            if let Some(surf) = surf {
-                if surf.is_supply() {
-                    return true;
-                }
-            }
-        }
-        false
+        self.face_surfs(fds_data).any(|surf| surf.is_supply())
     }
 }
 
@@ -867,63 +1127,67 @@ impl HasXB for Obst {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Part {
-    age: f64,
-    breakup: bool,
-    breakup_cnf_ramp_id: Option<String>,
-    breakup_distribution: String,
-    breakup_gamma_d: f64,
-    breakup_ratio: f64,
-    breakup_sigma_d: Option<f64>,
-    check_distribution: bool,
-    cnf_ramp_id: Option<String>,
-    color: String,
-    complex_refractive_index: f64,
-    ctrl_id: Option<String>,
-    dense_volume_fraction: f64,
-    devc_id: Option<String>,
-    diameter: Option<f64>,
-    distribution: String,
-    drag_coefficient: Vec<f64>,
-    drag_law: String,
-    free_area_fraction: Option<f64>,
-    fyi: Option<String>,
-    gamma_d: f64,
-    heat_of_combustion: Option<f64>,
-    horizontal_velocity: f64,
-    id: Option<String>,
-    initial_temperature: f64,
-    massless: bool,
-    maximum_diameter: f64,
-    minimum_diameter: f64,
-    monodisperse: bool,
-    n_strata: i64,
-    orientation: Vec<f64>,
-    permeability: Vec<f64>,
-    periodic_x: bool,
-    periodic_y: bool,
-    periodic_z: bool,
-    porous_volume_fraction: Option<f64>,
-    prop_id: Option<String>,
-    quantities: Vec<String>,
-    quantities_spec_id: Vec<String>,
-    radiative_property_table: Option<f64>,
-    real_refractive_index: f64,
-    rgb: Option<RGB>,
-    running_average_factor: f64,
-    sampling_factor: i64,
-    second_order_particle_transport: bool,
-    sigma_d: Option<f64>,
-    spec_id: Option<String>,
-    static_: bool,
-    surface_tension: f64,
-    surf_id: Option<String>,
-    target_only: bool,
-    turbulent_dispersion: bool,
-    vertical_velocity: f64,
+    pub age: Option<f64>,
+    pub breakup: Option<bool>,
+    pub breakup_cnf_ramp_id: Option<String>,
+    pub breakup_distribution: Option<String>,
+    pub breakup_gamma_d: Option<f64>,
+    pub breakup_ratio: Option<f64>,
+    pub breakup_sigma_d: Option<f64>,
+    pub check_distribution: Option<bool>,
+    pub cnf_ramp_id: Option<String>,
+    pub color: Option<String>,
+    pub complex_refractive_index: Option<f64>,
+    pub ctrl_id: Option<String>,
+    pub dense_volume_fraction: Option<f64>,
+    pub devc_id: Option<String>,
+    pub diameter: Option<f64>,
+    pub distribution: Option<String>,
+    pub drag_coefficient: Vec<f64>,
+    pub drag_law: Option<String>,
+    pub free_area_fraction: Option<f64>,
+    pub fyi: Option<String>,
+    pub gamma_d: Option<f64>,
+    pub heat_of_combustion: Option<f64>,
+    pub horizontal_velocity: Option<f64>,
+    pub id: Option<String>,
+    pub initial_temperature: Option<f64>,
+    pub massless: bool,
+    pub maximum_diameter: Option<f64>,
+    pub minimum_diameter: Option<f64>,
+    pub monodisperse: bool,
+    pub n_strata: Option<i64>,
+    pub orientation: Vec<f64>,
+    pub permeability: Vec<f64>,
+    pub periodic_x: Option<bool>,
+    pub periodic_y: Option<bool>,
+    pub periodic_z: Option<bool>,
+    pub porous_volume_fraction: Option<f64>,
+    pub prop_id: Option<String>,
+    pub quantities: Vec<String>,
+    pub quantities_spec_id: Vec<String>,
+    pub radiative_property_table: Option<String>,
+    pub real_refractive_index: Option<f64>,
+    pub rgb: Option<RGB>,
+    pub running_average_factor: Option<f64>,
+    pub sampling_factor: i64,
+    pub second_order_particle_transport: Option<bool>,
+    pub sigma_d: Option<f64>,
+    pub spec_id: Option<String>,
+    pub static_: bool,
+    pub surface_tension: Option<f64>,
+    pub surf_id: Option<String>,
+    pub target_only: Option<bool>,
+    pub turbulent_dispersion: Option<bool>,
+    pub vertical_velocity: Option<f64>,
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Pres {
     check_poisson: bool,
     fishpak_bc: Vec<i64>,
@@ -965,6 +1229,8 @@ pub struct Pres {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Prof {
     format_index: i64,
     fyi: String,
@@ -975,8 +1241,12 @@ pub struct Prof {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Prop {
+    /// Smoke detector activation obscuration.
     pub activation_obscuration: Option<f64>,
+    /// Activation temperature of a sprinkler or heat detector.
     pub activation_temperature: Option<f64>,
     // alpha_c: f64,
     // alpha_e: f64,
@@ -1027,6 +1297,7 @@ pub struct Prop {
     // pressure_ramp: String, // , PX : String
     // // , PXX : String
     pub quantity: Option<String>,
+    /// Response time index of a sprinkler or heat detector.
     pub rti: Option<f64>,
     // smokeview_id: Vec<String>,
     // smokeview_parameters: Vec<String>,
@@ -1046,6 +1317,8 @@ impl Prop {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Radi {
     angle_increment: i64,
     band_limits: Vec<f64>,
@@ -1071,30 +1344,47 @@ pub struct Radi {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Ramp {
-    id: String,
-    entries: Vec<RampEntry>,
+    pub id: String,
+    pub entries: Vec<RampEntry>,
 }
 
+/// A single RAMP namelist. A RAMP is made up of all the RAMP namelists which
+/// share the same ID.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RampEntry {
-    ctrl_id: String,
-    devc_id: String,
-    f: f64,
-    fyi: Option<String>,
-    number_interpolation_points: i64,
-    t: f64,
-    x: f64,
-    z: f64,
+    pub ctrl_id: Option<String>,
+    pub devc_id: Option<String>,
+    pub f: f64,
+    pub fyi: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default = "default_ramp_number_interpolation_points"))]
+    pub number_interpolation_points: i64,
+    /// Time, for ramps of time.
+    pub t: Option<f64>,
+    /// Position, for ramps of x.
+    pub x: Option<f64>,
+    /// Height, for ramps of z.
+    pub z: Option<f64>,
+}
+
+fn default_ramp_number_interpolation_points() -> i64 {
+    catalog::default_integer("RAMP", "NUMBER_INTERPOLATION_POINTS")
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Reac {
     // pub a: Option<f64>,
     // , ALT_REAC_ID : String
     // pub auto_ignition_temperature: f64,
     // pub c: f64,
     // pub check_atom_balance: bool,
+    /// Mass of CO produced per mass of fuel burned.
     pub co_yield: Option<f64>,
     // pub critical_flame_temperature: f64,
     // pub e: f64,
@@ -1106,12 +1396,12 @@ pub struct Reac {
     // , FLAME_SPEED_EXPONENT : String
     // , FLAME_SPEED_TEMPERATURE : String
     // pub formula: String,
-    // pub fuel: String,
+    pub fuel: Option<String>,
     // pub fuel_radcal_id: String,
     // , FWD_ID : String
     // pub fyi: Option<String>,
     // pub h: f64,
-    // pub heat_of_combustion: f64,
+    pub heat_of_combustion: Option<f64>,
     // pub id: Option<String>,
     // pub ideal: bool,
     // pub n: f64,
@@ -1127,9 +1417,10 @@ pub struct Reac {
     // pub reac_mass_error: f64,
     // , REVERSE : String
     // pub soot_h_fraction: f64,
+    /// Mass of soot produced per mass of fuel burned.
     pub soot_yield: Option<f64>,
     // pub spec_id_n_s: Vec<String>,
-    // pub spec_id_nu: Vec<String>,
+    pub spec_id_nu: Vec<String>,
     // , TABLE_FS : String
     // , TAU_CHEM : String
     // , TAU_FLAME : String
@@ -1140,78 +1431,124 @@ pub struct Reac {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Slcf {
-    agl_slice: String,
-    cell_centered: bool,
-    evacuation: bool, // , FACE_CENTERED : String
+    pub agl_slice: Option<f64>,
+    pub cell_centered: bool,
+    pub evacuation: Option<bool>, // , FACE_CENTERED : String
     // , FIRE_LINE : String
-    fyi: Option<String>,
-    id: Option<String>,
-    ior: i64,
-    level_set_fire_line: String,
-    maximum_value: f64,
-    mesh_number: i64,
-    minimum_value: f64,
-    part_id: String,
-    pbx: Option<f64>,
-    pby: Option<f64>,
-    pbz: Option<f64>, // , PROP_ID : String
-    quantity: Option<String>,
-    quantity2: Option<String>,
-    reac_id: String, // , SLICETYPE : String
-    spec_id: Option<String>,
-    vector: bool,
-    velo_index: i64,
-    xb: XB,
+    pub fyi: Option<String>,
+    pub id: Option<String>,
+    pub ior: Option<i64>,
+    pub level_set_fire_line: Option<String>,
+    pub maximum_value: Option<f64>,
+    pub mesh_number: Option<i64>,
+    pub minimum_value: Option<f64>,
+    pub part_id: Option<String>,
+    /// The x coordinate of a slice plane normal to x.
+    pub pbx: Option<f64>,
+    /// The y coordinate of a slice plane normal to y.
+    pub pby: Option<f64>,
+    /// The z coordinate of a slice plane normal to z.
+    pub pbz: Option<f64>, // , PROP_ID : String
+    pub quantity: Option<String>,
+    pub quantity2: Option<String>,
+    pub reac_id: Option<String>, // , SLICETYPE : String
+    pub spec_id: Option<String>,
+    pub vector: bool,
+    pub velo_index: i64,
+    /// The extent of the slice.
+    pub xb: Option<XB>,
+}
+
+impl Default for Slcf {
+    fn default() -> Self {
+        Slcf {
+            agl_slice: None,
+            cell_centered: catalog::default_logical("SLCF", "CELL_CENTERED"),
+            evacuation: None,
+            fyi: None,
+            id: None,
+            ior: None,
+            level_set_fire_line: None,
+            maximum_value: None,
+            mesh_number: None,
+            minimum_value: None,
+            part_id: None,
+            pbx: None,
+            pby: None,
+            pbz: None,
+            quantity: None,
+            quantity2: None,
+            reac_id: None,
+            spec_id: None,
+            vector: catalog::default_logical("SLCF", "VECTOR"),
+            velo_index: catalog::default_integer("SLCF", "VELO_INDEX"),
+            xb: None,
+        }
+    }
+}
+
+impl MightHaveXB for Slcf {
+    fn try_xb(&self) -> Option<XB> {
+        self.xb
+    }
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Spec {
-    aerosol: bool,
-    alias: String,
-    background: bool, // , COPY_LUMPED : String
-    conductivity: f64,
-    conductivity_solid: f64,
-    density_liquid: f64,
-    density_solid: f64,
-    diffusivity: f64,
-    enthalpy_of_formation: f64,
-    epsilonklj: f64,
-    fic_concentration: f64,
-    fld_lethal_dose: f64,
-    formula: String,
-    fyi: Option<String>,
-    heat_of_vaporization: f64,
-    h_v_reference_temperature: f64,
-    id: String,
-    lumped_component_only: bool,
-    mass_extinction_coefficient: f64,
-    mass_fraction: Vec<f64>,
-    mass_fraction_0: f64, // , MAX_DIAMETER : String
-    mean_diameter: f64,
-    melting_temperature: f64, // , MIN_DIAMETER : String
-    mw: f64,                  // , N_BINS : String
-    pr_gas: f64,
-    primitive: bool,
-    radcal_id: String,
-    ramp_cp: String,
-    ramp_cp_l: String,
-    ramp_d: String,
-    ramp_g_f: String,
-    ramp_k: String,
-    ramp_mu: String,
-    reference_enthalpy: f64,
-    reference_temperature: f64,
-    sigmalj: f64,
-    spec_id: Vec<String>,
-    specific_heat: f64,
-    specific_heat_liquid: f64,
-    vaporization_temperature: f64,
-    viscosity: f64,
-    volume_fraction: Vec<f64>,
+    pub aerosol: bool,
+    pub alias: Option<String>,
+    pub background: bool, // , COPY_LUMPED : String
+    pub conductivity: Option<f64>,
+    pub conductivity_solid: Option<f64>,
+    pub density_liquid: Option<f64>,
+    pub density_solid: Option<f64>,
+    pub diffusivity: Option<f64>,
+    pub enthalpy_of_formation: Option<f64>,
+    pub epsilonklj: Option<f64>,
+    pub fic_concentration: Option<f64>,
+    pub fld_lethal_dose: Option<f64>,
+    pub formula: Option<String>,
+    pub fyi: Option<String>,
+    pub heat_of_vaporization: Option<f64>,
+    pub h_v_reference_temperature: Option<f64>,
+    pub id: Option<String>,
+    pub lumped_component_only: Option<bool>,
+    pub mass_extinction_coefficient: Option<f64>,
+    pub mass_fraction: Vec<f64>,
+    pub mass_fraction_0: Option<f64>, // , MAX_DIAMETER : String
+    pub mean_diameter: Option<f64>,
+    pub melting_temperature: Option<f64>, // , MIN_DIAMETER : String
+    pub mw: Option<f64>,                  // , N_BINS : String
+    pub pr_gas: Option<f64>,
+    pub primitive: Option<bool>,
+    pub radcal_id: Option<String>,
+    pub ramp_cp: Option<String>,
+    pub ramp_cp_l: Option<String>,
+    pub ramp_d: Option<String>,
+    pub ramp_g_f: Option<String>,
+    pub ramp_k: Option<String>,
+    pub ramp_mu: Option<String>,
+    pub reference_enthalpy: Option<f64>,
+    pub reference_temperature: Option<f64>,
+    pub sigmalj: Option<f64>,
+    pub spec_id: Vec<String>,
+    pub specific_heat: Option<f64>,
+    pub specific_heat_liquid: Option<f64>,
+    pub vaporization_temperature: Option<f64>,
+    pub viscosity: Option<f64>,
+    pub volume_fraction: Vec<f64>,
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Surf {
     pub adiabatic: bool,
     pub auto_ignition_temperature: f64,
@@ -1256,7 +1593,7 @@ pub struct Surf {
     // pub mass_flux_var: Option<f64>,
     // pub mass_fraction: Vec<f64>,
     // pub mass_transfer_coefficient: f64,
-    // pub matl_id: Vec<String>,
+    pub matl_id: Vec<String>,
     // pub matl_mass_fraction: Vec<f64>,
     // pub minimum_layer_thickness: f64,
     pub mlrpua: Option<f64>,
@@ -1266,28 +1603,29 @@ pub struct Surf {
     // no_slip: bool,
     // nppc: i64,
     // particle_mass_flux: f64,
-    // part_id: String,
+    pub part_id: Option<String>,
     // ple: f64,
     // profile: String,
     // radius: f64,
     // ramp_ef: String,
     // ramp_mf: Vec<String>,
     // ramp_part: String,
-    // ramp_q: Option<String>,
-    // ramp_t: Option<String>,
+    pub ramp_q: Option<String>,
+    pub ramp_t: Option<String>,
     // ramp_t_i: Option<String>,
-    // ramp_v: Option<String>,
+    pub ramp_v: Option<String>,
     // ramp_v_x: Option<String>,
     // ramp_v_y: Option<String>,
     // ramp_v_z: Option<String>,
     // rgb: RGB,
     // roughness: f64,
-    // spec_id: String,
+    pub spec_id: Vec<String>,
     // spread_rate: f64,
     // stretch_factor: f64,
     // tau_ef: f64,
     // tau_mf: f64,
     // tau_part: f64,
+    /// The ramp-up time of the heat release rate.
     pub tau_q: Option<f64>,
     // tau_t: f64,
     // tau_v: f64,
@@ -1329,10 +1667,12 @@ pub struct Surf {
     // veg_lset_ellipse: f64,
     // veg_lset_tan2: bool,
     // veg_lset_ellipse_head: f64,
+    /// Normal velocity, negative for flow into the domain.
     pub vel: Option<f64>,
     // vel_bulk: f64,
     // vel_grad: f64,
     // vel_t: Option<(f64, f64)>,
+    /// Volume flow rate, negative for flow into the domain.
     pub volume_flow: Option<f64>,
     // width: f64,
     // xyz: XYZ,
@@ -1367,8 +1707,8 @@ impl Surf {
 impl Default for Surf {
     fn default() -> Self {
         Surf {
-            adiabatic: true,
-            auto_ignition_temperature: -273_f64,
+            adiabatic: catalog::default_logical("SURF", "ADIABATIC"),
+            auto_ignition_temperature: catalog::default_real("SURF", "AUTO_IGNITION_TEMPERATURE"),
             // backing: "EXPOSED".to_string(),
             // burn_away: false,
             // cell_size_factor: 1_f64,
@@ -1411,7 +1751,7 @@ impl Default for Surf {
             // mass_flux_var: None,
             // mass_fraction: vec![],
             // mass_transfer_coefficient: None,
-            // matl_id: vec![],
+            matl_id: vec![],
             // matl_mass_fraction: vec![],
             // minimum_layer_thickness: 1e-6_f64,
             mlrpua: None,
@@ -1421,23 +1761,23 @@ impl Default for Surf {
             // no_slip: false,
             // nppc: 1_i64,
             // particle_mass_flux: None,
-            // part_id: None,
+            part_id: None,
             // ple: 0.3_f64,
             // profile: None,
             // radius: None,
             // ramp_ef: None,
             // ramp_mf: vec![],
             // ramp_part: None,
-            // ramp_q: None,
-            // ramp_t: None,
+            ramp_q: None,
+            ramp_t: None,
             // ramp_t_i: None,
-            // ramp_v: None,
+            ramp_v: None,
             // ramp_v_x: None,
             // ramp_v_y: None,
             // ramp_v_z: None,
             // rgb: RGB {r:255_i64, g: 204_i64, b: 102_i64},
             // roughness: 0_f64,
-            // spec_id: None,
+            spec_id: vec![],
             // spread_rate: None,
             // stretch_factor: 2_f64,
             // tau_ef: 1_f64,
@@ -1532,6 +1872,8 @@ impl Default for Surf {
 //     | NoBurner
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Tabl {
     fyi: Option<String>,
     id: String,
@@ -1539,74 +1881,156 @@ pub struct Tabl {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Time {
-    // pub dt: Option<f64>,
+    /// The initial time step.
+    pub dt: Option<f64>,
     // pub evac_dt_flowfield: f64,
     // pub evac_dt_steady_state: f64,
-    // pub fyi: Option<String>,
-    // pub limiting_dt_ratio: f64,
-    // pub lock_time_step: bool,
-    // pub restrict_time_step: bool,
-    pub t_begin: Option<f64>,
-    pub t_end: Option<f64>,
-    // pub t_end_geom: f64,
-    // pub time_shrink_factor: f64,
-    // pub wall_increment: i64,
+    pub fyi: Option<String>,
+    pub limiting_dt_ratio: f64,
+    pub lock_time_step: bool,
+    pub restrict_time_step: bool,
+    pub t_begin: f64,
+    pub t_end: f64,
+    /// The time at which the geometry stops moving.
+    pub t_end_geom: Option<f64>,
+    pub time_shrink_factor: f64,
+    pub wall_increment: i64,
     // pub wall_increment_ht3d: i64,
     // pub twfin: f64,
 }
 
-fn decode_time(fds_file: &mut FDSFile, namelist: &Namelist) {
-    let time = Time {
-        t_begin: namelist
-            .parameters
-            .get("T_BEGIN")
-            .cloned()
-            .and_then(|x| x.try_into().ok()),
-        t_end: namelist
-            .parameters
-            .get("T_END")
-            .cloned()
-            .and_then(|x| x.try_into().ok()),
-    };
+impl Time {
+    /// The length of simulated time, from T_BEGIN to T_END.
+    pub fn duration(&self) -> f64 {
+        self.t_end - self.t_begin
+    }
+}
+
+impl Default for Time {
+    /// The FDS defaults. FDS also uses these when there is no TIME namelist,
+    /// although [`FDSFile::time`] is then [`None`].
+    fn default() -> Self {
+        Time {
+            dt: None,
+            fyi: None,
+            limiting_dt_ratio: catalog::default_real("TIME", "LIMITING_DT_RATIO"),
+            lock_time_step: catalog::default_logical("TIME", "LOCK_TIME_STEP"),
+            restrict_time_step: catalog::default_logical("TIME", "RESTRICT_TIME_STEP"),
+            t_begin: catalog::default_real("TIME", "T_BEGIN"),
+            t_end: catalog::default_real("TIME", "T_END"),
+            t_end_geom: None,
+            time_shrink_factor: catalog::default_real("TIME", "TIME_SHRINK_FACTOR"),
+            wall_increment: catalog::default_integer("TIME", "WALL_INCREMENT"),
+        }
+    }
+}
+
+fn decode_time(fds_file: &mut FDSFile, namelist: &Namelist) -> Result<(), DecodeErrorKind> {
     if fds_file.time.is_some() {
-        panic!("Multiple TIME namelists")
+        return Err(DecodeErrorKind::Repeated);
     }
+    let default = Time::default();
+    let time = Time {
+        dt: get_parameter(namelist, "DT")?,
+        fyi: get_parameter(namelist, "FYI")?,
+        limiting_dt_ratio: get_parameter(namelist, "LIMITING_DT_RATIO")?
+            .unwrap_or(default.limiting_dt_ratio),
+        lock_time_step: get_parameter(namelist, "LOCK_TIME_STEP")?
+            .unwrap_or(default.lock_time_step),
+        restrict_time_step: get_parameter(namelist, "RESTRICT_TIME_STEP")?
+            .unwrap_or(default.restrict_time_step),
+        t_begin: get_parameter(namelist, "T_BEGIN")?.unwrap_or(default.t_begin),
+        t_end: get_parameter(namelist, "T_END")?.unwrap_or(default.t_end),
+        t_end_geom: get_parameter(namelist, "T_END_GEOM")?,
+        time_shrink_factor: get_parameter(namelist, "TIME_SHRINK_FACTOR")?
+            .unwrap_or(default.time_shrink_factor),
+        wall_increment: get_parameter(namelist, "WALL_INCREMENT")?
+            .unwrap_or(default.wall_increment),
+    };
     fds_file.time = Some(time);
+    Ok(())
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Trnx {
-    cc: f64,
-    fyi: Option<String>,
-    ideriv: i64,
-    mesh_number: i64,
-    pc: f64,
+    /// Computational coordinate.
+    pub cc: f64,
+    pub fyi: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default = "default_trnx_ideriv"))]
+    pub ideriv: i64,
+    #[cfg_attr(feature = "serde", serde(default = "default_trnx_mesh_number"))]
+    pub mesh_number: i64,
+    /// Physical coordinate.
+    pub pc: f64,
+}
+
+fn default_trnx_ideriv() -> i64 {
+    catalog::default_integer("TRNX", "IDERIV")
+}
+
+fn default_trnx_mesh_number() -> i64 {
+    catalog::default_integer("TRNX", "MESH_NUMBER")
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Trny {
-    cc: f64,
-    fyi: Option<String>,
-    ideriv: i64,
-    mesh_number: i64,
-    pc: f64,
+    /// Computational coordinate.
+    pub cc: f64,
+    pub fyi: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default = "default_trny_ideriv"))]
+    pub ideriv: i64,
+    #[cfg_attr(feature = "serde", serde(default = "default_trny_mesh_number"))]
+    pub mesh_number: i64,
+    /// Physical coordinate.
+    pub pc: f64,
+}
+
+fn default_trny_ideriv() -> i64 {
+    catalog::default_integer("TRNY", "IDERIV")
+}
+
+fn default_trny_mesh_number() -> i64 {
+    catalog::default_integer("TRNY", "MESH_NUMBER")
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Trnz {
-    cc: f64,
-    fyi: Option<String>,
-    ideriv: i64,
-    mesh_number: i64,
-    pc: f64,
+    /// Computational coordinate.
+    pub cc: f64,
+    pub fyi: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default = "default_trnz_ideriv"))]
+    pub ideriv: i64,
+    #[cfg_attr(feature = "serde", serde(default = "default_trnz_mesh_number"))]
+    pub mesh_number: i64,
+    /// Physical coordinate.
+    pub pc: f64,
+}
+
+fn default_trnz_ideriv() -> i64 {
+    catalog::default_integer("TRNZ", "IDERIV")
+}
+
+fn default_trnz_mesh_number() -> i64 {
+    catalog::default_integer("TRNZ", "MESH_NUMBER")
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Vent {
     // color: Option<String>,
-    // ctrl_id: String,
-    // devc_id: String,
+    pub ctrl_id: Option<String>,
+    pub devc_id: Option<String>,
     // dynamic_pressure: f64,
     // evacuation: bool,
     // fyi: Option<String>,
@@ -1615,8 +2039,8 @@ pub struct Vent {
     // l_eddy: f64,
     // l_eddy_ij: Vec<i64>,
     // mb: String,
-    // mesh_id: String,
-    // mult_id: String,
+    pub mesh_id: Option<String>,
+    pub mult_id: Option<String>,
     // n_eddy: i64,
     // outline: bool,
     // pbx: f64,
@@ -1635,6 +2059,7 @@ pub struct Vent {
     // uvw: Vec<f64>,
     // vel_rms: f64,
     // // , WIND : String
+    /// The extent of the vent.
     pub xb: Option<XB>,
     // xyz: XYZ,
 }
@@ -1757,23 +2182,31 @@ impl MightHaveXB for &mut Vent {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Zone {
     pub id: String,
     pub leak_area: f64,
     pub leak_pressure_exponent: f64,
+    /// Reference pressure for the leakage area.
     pub leak_reference_pressure: f64,
+    /// The extent of the pressure zone.
     pub xb: XB,
     pub periodic: bool,
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Plane {
     X,
     Y,
     Z,
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Direction {
     NegX,
     PosX,
@@ -1783,7 +2216,29 @@ pub enum Direction {
     PosZ,
 }
 
+impl Direction {
+    /// Every direction, in the order FDS uses for SURF_ID6.
+    pub const ALL: [Direction; 6] = [
+        Direction::NegX,
+        Direction::PosX,
+        Direction::NegY,
+        Direction::PosY,
+        Direction::NegZ,
+        Direction::PosZ,
+    ];
+
+    pub fn axis(&self) -> Plane {
+        match self {
+            Direction::NegX | Direction::PosX => Plane::X,
+            Direction::NegY | Direction::PosY => Plane::Y,
+            Direction::NegZ | Direction::PosZ => Plane::Z,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct IJK {
     pub i: i64,
     pub j: i64,
@@ -1791,9 +2246,15 @@ pub struct IJK {
 }
 
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum FromParameterValueError {
     ExpectedArray,
     IncorrectArrayLength { expected: usize, found: usize },
+    /// The array has no element at this (1-based) index.
+    MissingElement { index: usize },
+    /// The element at this (1-based) index is not of the expected type.
+    InvalidElement { index: usize },
 }
 
 impl std::fmt::Display for FromParameterValueError {
@@ -1803,6 +2264,8 @@ impl std::fmt::Display for FromParameterValueError {
             Self::IncorrectArrayLength { expected, found } => {
                 write!(f, "Expected array length of {} found {}", found, expected)
             }
+            Self::MissingElement { index } => write!(f, "Array has no element {}", index),
+            Self::InvalidElement { index } => write!(f, "Array element {} is invalid", index),
         }
     }
 }
@@ -1812,6 +2275,8 @@ impl std::error::Error for FromParameterValueError {
         match *self {
             Self::ExpectedArray => None,
             Self::IncorrectArrayLength { .. } => None,
+            Self::MissingElement { .. } => None,
+            Self::InvalidElement { .. } => None,
         }
     }
 }
@@ -1844,13 +2309,53 @@ impl TryFrom<ParameterValue> for IJK {
 }
 
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct RGB {
     pub r: i64,
     pub g: i64,
     pub b: i64,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+impl TryFrom<ParameterValue> for RGB {
+    type Error = FromParameterValueError;
+
+    fn try_from(pv: ParameterValue) -> Result<Self, Self::Error> {
+        match pv {
+            ParameterValue::Atom(_) => Err(FromParameterValueError::ExpectedArray),
+            ParameterValue::Array(vmap) => match vmap.len() {
+                3 => {
+                    let element = |atom: Option<&_>, index| -> Result<i64, Self::Error> {
+                        let atom = atom
+                            .cloned()
+                            .ok_or(FromParameterValueError::MissingElement { index })?;
+                        ParameterValue::Atom(atom)
+                            .try_into()
+                            .map_err(|_| FromParameterValueError::InvalidElement { index })
+                    };
+                    Ok(RGB {
+                        r: element(vmap.get(&vec![1]), 1)?,
+                        g: element(vmap.get(&vec![2]), 2)?,
+                        b: element(vmap.get(&vec![3]), 3)?,
+                    })
+                }
+                l => Err(FromParameterValueError::IncorrectArrayLength {
+                    expected: 3,
+                    found: l,
+                }),
+            },
+        }
+    }
+}
+
+/// A box given by its bounds along each axis. FDS accepts the bounds along an
+/// axis in either order, but the methods here assume that they are sorted
+/// (i.e. `x1 <= x2`), which [`XB::new`] and decoding ensure. Whether the bounds
+/// were originally given in reverse order is kept in `reversed` so the XB can
+/// be written back as it was.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct XB {
     pub x1: Coord,
     pub x2: Coord,
@@ -1858,9 +2363,151 @@ pub struct XB {
     pub y2: Coord,
     pub z1: Coord,
     pub z2: Coord,
+    pub reversed: Reversed,
+}
+
+/// The axes along which the bounds of an [`XB`] were given in reverse order.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Reversed {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+}
+
+/// XBs are equal if they cover the same box, regardless of the order in which
+/// their bounds were originally given.
+impl PartialEq for XB {
+    fn eq(&self, other: &XB) -> bool {
+        self.x1 == other.x1
+            && self.x2 == other.x2
+            && self.y1 == other.y1
+            && self.y2 == other.y2
+            && self.z1 == other.z1
+            && self.z2 == other.z2
+    }
 }
 
 impl XB {
+    /// Create a normalized XB from bounds which may be in either order.
+    pub fn new(x1: Coord, x2: Coord, y1: Coord, y2: Coord, z1: Coord, z2: Coord) -> XB {
+        XB {
+            x1,
+            x2,
+            y1,
+            y2,
+            z1,
+            z2,
+            reversed: Reversed::default(),
+        }
+        .normalized()
+    }
+
+    /// A copy of this XB with the bounds along each axis sorted. Any axes which
+    /// are swapped are recorded in `reversed`.
+    pub fn normalized(&self) -> XB {
+        XB {
+            x1: self.x1.min(self.x2),
+            x2: self.x1.max(self.x2),
+            y1: self.y1.min(self.y2),
+            y2: self.y1.max(self.y2),
+            z1: self.z1.min(self.z2),
+            z2: self.z1.max(self.z2),
+            reversed: Reversed {
+                x: self.reversed.x != (self.x1 > self.x2),
+                y: self.reversed.y != (self.y1 > self.y2),
+                z: self.reversed.z != (self.z1 > self.z2),
+            },
+        }
+    }
+
+    /// The XB with its bounds in the order in which they were originally
+    /// given.
+    pub fn original(&self) -> XB {
+        let mut xb = *self;
+        if self.reversed.x {
+            std::mem::swap(&mut xb.x1, &mut xb.x2);
+        }
+        if self.reversed.y {
+            std::mem::swap(&mut xb.y1, &mut xb.y2);
+        }
+        if self.reversed.z {
+            std::mem::swap(&mut xb.z1, &mut xb.z2);
+        }
+        xb.reversed = Reversed::default();
+        xb
+    }
+
+    /// Test if the XB has zero thickness along any axis, and therefore no
+    /// volume.
+    pub fn is_degenerate(&self) -> bool {
+        self.x1 == self.x2 || self.y1 == self.y2 || self.z1 == self.z2
+    }
+
+    /// The plane in which the XB lies, if it has zero thickness along exactly
+    /// one axis (as a VENT should).
+    pub fn plane(&self) -> Option<Plane> {
+        match (self.x1 == self.x2, self.y1 == self.y2, self.z1 == self.z2) {
+            (true, false, false) => Some(Plane::X),
+            (false, true, false) => Some(Plane::Y),
+            (false, false, true) => Some(Plane::Z),
+            _ => None,
+        }
+    }
+
+    pub fn volume(&self) -> f64 {
+        (self.x2 - self.x1) * (self.y2 - self.y1) * (self.z2 - self.z1)
+    }
+
+    pub fn center(&self) -> XYZ {
+        XYZ {
+            x: (self.x1 + self.x2) / 2.0,
+            y: (self.y1 + self.y2) / 2.0,
+            z: (self.z1 + self.z2) / 2.0,
+        }
+    }
+
+    /// The smallest XB which contains both XBs.
+    pub fn union(&self, b: &XB) -> XB {
+        XB::new(
+            self.x1.min(b.x1),
+            self.x2.max(b.x2),
+            self.y1.min(b.y1),
+            self.y2.max(b.y2),
+            self.z1.min(b.z1),
+            self.z2.max(b.z2),
+        )
+    }
+
+    /// The region common to both XBs. XBs which only touch have a degenerate
+    /// intersection, and XBs which do not touch have none.
+    pub fn intersection(&self, b: &XB) -> Option<XB> {
+        let xb = XB::new(
+            self.x1.max(b.x1),
+            self.x2.min(b.x2),
+            self.y1.max(b.y1),
+            self.y2.min(b.y2),
+            self.z1.max(b.z1),
+            self.z2.min(b.z2),
+        );
+        if xb.reversed == Reversed::default() {
+            Some(xb)
+        } else {
+            None
+        }
+    }
+
+    /// Test if a point is within the XB, including on its boundary.
+    pub fn contains_point(&self, point: XYZ) -> bool {
+        self.x1 <= point.x
+            && point.x <= self.x2
+            && self.y1 <= point.y
+            && point.y <= self.y2
+            && self.z1 <= point.z
+            && point.z <= self.z2
+    }
+
     /// Test if two XBs intersect (i.e. their bounding boxes). Two bounding boxes
     /// intersect of all 3 dimensions have overlap. EQ is considered overlap.
     pub fn intersect(&self, b: &XB) -> bool {
@@ -1869,6 +2516,71 @@ impl XB {
         let intersect_z = (self.z2 > b.z1) && (b.z2 > self.z1);
         intersect_x && intersect_y && intersect_z
     }
+
+    /// The lower and upper bounds along an axis.
+    pub fn bounds(&self, axis: Plane) -> (Coord, Coord) {
+        match axis {
+            Plane::X => (self.x1, self.x2),
+            Plane::Y => (self.y1, self.y2),
+            Plane::Z => (self.z1, self.z2),
+        }
+    }
+
+    /// A copy of this XB with the bounds along one axis replaced.
+    pub fn with_bounds(&self, axis: Plane, lower: Coord, upper: Coord) -> XB {
+        let mut xb = *self;
+        match axis {
+            Plane::X => {
+                xb.x1 = lower;
+                xb.x2 = upper;
+            }
+            Plane::Y => {
+                xb.y1 = lower;
+                xb.y2 = upper;
+            }
+            Plane::Z => {
+                xb.z1 = lower;
+                xb.z2 = upper;
+            }
+        }
+        xb
+    }
+
+    /// The parts of this XB which are not within `b`, as a set of
+    /// non-overlapping boxes. Unlike [`XB::intersect`], an axis along which
+    /// this XB has no thickness (such as the normal of a vent) counts as
+    /// overlapping if it lies within or on the bounds of `b`.
+    pub fn difference(&self, b: &XB) -> Vec<XB> {
+        let axes = [Plane::X, Plane::Y, Plane::Z];
+        for &axis in axes.iter() {
+            let (a1, a2) = self.bounds(axis);
+            let (b1, b2) = b.bounds(axis);
+            let overlaps = if a1 == a2 {
+                b1 <= a1 && a1 <= b2
+            } else {
+                a2 > b1 && b2 > a1
+            };
+            if !overlaps {
+                return vec![*self];
+            }
+        }
+        let mut pieces = Vec::new();
+        let mut remaining = *self;
+        for &axis in axes.iter() {
+            let (mut r1, mut r2) = remaining.bounds(axis);
+            let (b1, b2) = b.bounds(axis);
+            if r1 < b1 {
+                pieces.push(remaining.with_bounds(axis, r1, b1));
+                r1 = b1;
+            }
+            if r2 > b2 {
+                pieces.push(remaining.with_bounds(axis, b2, r2));
+                r2 = b2;
+            }
+            remaining = remaining.with_bounds(axis, r1, r2);
+        }
+        pieces
+    }
 }
 
 impl TryFrom<ParameterValue> for XB {
@@ -1878,26 +2590,26 @@ impl TryFrom<ParameterValue> for XB {
         match pv {
             ParameterValue::Atom(_) => Err(FromParameterValueError::ExpectedArray),
             ParameterValue::Array(vmap) => match vmap.len() {
-                6 => Ok(XB {
-                    x1: ParameterValue::Atom(vmap.get(&vec![1]).unwrap().clone())
+                6 => Ok(XB::new(
+                    ParameterValue::Atom(vmap.get(&vec![1]).unwrap().clone())
                         .try_into()
                         .expect("x1 failed"),
-                    x2: ParameterValue::Atom(vmap.get(&vec![2]).unwrap().clone())
+                    ParameterValue::Atom(vmap.get(&vec![2]).unwrap().clone())
                         .try_into()
                         .expect("x2 failed"),
-                    y1: ParameterValue::Atom(vmap.get(&vec![3]).unwrap().clone())
+                    ParameterValue::Atom(vmap.get(&vec![3]).unwrap().clone())
                         .try_into()
                         .expect("y1 failed"),
-                    y2: ParameterValue::Atom(vmap.get(&vec![4]).unwrap().clone())
+                    ParameterValue::Atom(vmap.get(&vec![4]).unwrap().clone())
                         .try_into()
                         .expect("y2 failed"),
-                    z1: ParameterValue::Atom(vmap.get(&vec![5]).unwrap().clone())
+                    ParameterValue::Atom(vmap.get(&vec![5]).unwrap().clone())
                         .try_into()
                         .expect("z1 failed"),
-                    z2: ParameterValue::Atom(vmap.get(&vec![6]).unwrap().clone())
+                    ParameterValue::Atom(vmap.get(&vec![6]).unwrap().clone())
                         .try_into()
                         .expect("z2 failed"),
-                }),
+                )),
                 l => Err(FromParameterValueError::IncorrectArrayLength {
                     expected: 6,
                     found: l,
@@ -1907,7 +2619,9 @@ impl TryFrom<ParameterValue> for XB {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct XYZ {
     pub x: Coord,
     pub y: Coord,
@@ -1943,21 +2657,28 @@ impl TryFrom<ParameterValue> for XYZ {
 
 type Coord = f64;
 
-type GridCoord = i64;
+pub type GridCoord = i64;
 
 fn simple_surf(name: String) -> Surf {
     Surf {
         id: Some(name),
+        // FDS holds INERT at the ambient temperature, and the other built-in
+        // SURFs have no solid phase, so none of them is insulated.
+        adiabatic: false,
         ..Default::default()
     }
 }
+/// The SURF IDs which FDS defines without a SURF namelist.
+pub const BUILTIN_SURF_IDS: [&str; 5] = ["INERT", "OPEN", "HVAC", "MIRROR", "PERIODIC"];
+
 /// Convert a ['NamelistFile'] to an ['FDSFile'].
 pub fn decode_fds_file(namelist_file: &NamelistFile) -> FDSFile {
-    let inert_surf = simple_surf("INERT".to_string());
-    let open_surf = simple_surf("OPEN".to_string());
-    let hvac_surf = simple_surf("HVAC".to_string());
     let mut fds_file = FDSFile {
-        surfs: vec![inert_surf, open_surf, hvac_surf],
+        surfs: BUILTIN_SURF_IDS
+            .iter()
+            .map(|id| simple_surf(id.to_string()))
+            .collect(),
+        builtin_surfs: BUILTIN_SURF_IDS.len(),
         ..Default::default()
     };
     for namelist in namelist_file.namelists.iter() {
@@ -1967,38 +2688,183 @@ pub fn decode_fds_file(namelist_file: &NamelistFile) -> FDSFile {
 }
 
 fn decode_namelist(fds_file: &mut FDSFile, namelist: &Namelist) {
+    record_unknown_parameters(fds_file, namelist);
     match namelist.name.as_ref() {
         "OBST" => decode_obst(fds_file, namelist),
+        "HOLE" => decode_or_record(fds_file, namelist, decode_hole),
         "VENT" => decode_vent(fds_file, namelist),
         "DEVC" => decode_devc(fds_file, namelist),
-        // "PART" => decode_part(fds_file, namelist),
-        "TIME" => decode_time(fds_file, namelist),
+        "CTRL" => decode_or_record(fds_file, namelist, decode_ctrl),
+        "PART" => decode_or_record(fds_file, namelist, decode_part),
+        "TIME" => decode_or_record(fds_file, namelist, decode_time),
         "PROP" => decode_prop(fds_file, namelist),
         "SURF" => decode_surf(fds_file, namelist),
+        "MATL" => decode_or_record(fds_file, namelist, decode_matl),
+        "SPEC" => decode_or_record(fds_file, namelist, decode_spec),
+        "RAMP" => decode_or_record(fds_file, namelist, decode_ramp),
+        "MULT" => decode_or_record(fds_file, namelist, decode_mult),
         "MESH" => decode_mesh(fds_file, namelist),
-        // "SLCF" => decode_slcf(fds_file, namelist),
+        "TRNX" => decode_or_record(fds_file, namelist, decode_trnx),
+        "TRNY" => decode_or_record(fds_file, namelist, decode_trny),
+        "TRNZ" => decode_or_record(fds_file, namelist, decode_trnz),
+        "SLCF" => decode_slcf(fds_file, namelist),
         "REAC" => decode_reac(fds_file, namelist),
         // "HVAC" => decode_hvac(fds_file, namelist),
         // "DUMP" => decode_dump(fds_file, namelist),
-        // "MISC" => decode_misc(fds_file, namelist),
+        "MISC" => decode_or_record(fds_file, namelist, decode_misc),
         "HEAD" => decode_head(fds_file, namelist),
         _ => decode_unknown(fds_file, namelist),
     }
 }
 
+/// Record the parameters of a namelist which are not in the catalog for its
+/// group. Namelist groups which are not in the catalog are not checked.
+fn record_unknown_parameters(fds_file: &mut FDSFile, namelist: &Namelist) {
+    let group = match catalog::group(namelist.name.as_str()) {
+        Some(group) => group,
+        None => return,
+    };
+    let mut unknown: Vec<&String> = namelist
+        .parameters
+        .keys()
+        .filter(|name| group.parameter(name).is_none())
+        .collect();
+    unknown.sort();
+    let id: Option<String> = namelist
+        .parameters
+        .get("ID")
+        .cloned()
+        .and_then(|x| x.try_into().ok());
+    for parameter in unknown {
+        // These are reported by FDSFile::validate_names, so only trace them
+        // here.
+        log::debug!(
+            namelist = group.name,
+            id:? = id,
+            parameter = parameter.as_str();
+            "parameter is not valid for this namelist group"
+        );
+        fds_file.unknown_parameters.push(UnknownParameter {
+            namelist: group.name.to_string(),
+            id: id.clone(),
+            parameter: parameter.clone(),
+        });
+    }
+}
+
+/// Decode a namelist, or if it cannot be decoded keep it as unknown and record
+/// why.
+fn decode_or_record(
+    fds_file: &mut FDSFile,
+    namelist: &Namelist,
+    decode: fn(&mut FDSFile, &Namelist) -> Result<(), DecodeErrorKind>,
+) {
+    if let Err(kind) = decode(fds_file, namelist) {
+        let error = DecodeError {
+            namelist: namelist.name.clone(),
+            id: namelist
+                .parameters
+                .get("ID")
+                .cloned()
+                .and_then(|x| x.try_into().ok()),
+            kind,
+        };
+        log::warn!(error:% = error; "namelist could not be decoded, keeping it as unknown");
+        fds_file.decode_errors.push(error);
+        fds_file.unknown_namelists.push(namelist.clone());
+    }
+}
+
 fn decode_unknown(fds_file: &mut FDSFile, namelist: &Namelist) {
+    log::debug!(
+        namelist = namelist.name.as_str();
+        "namelist is not decoded, keeping it as unknown"
+    );
     fds_file.unknown_namelists.push(namelist.clone());
 }
 
+/// Convert a parameter value into a list of values ordered by their indices. A
+/// single atom is treated as a list of one value.
+fn parameter_vec<T>(pv: ParameterValue) -> Result<Vec<T>, FromParameterValueError>
+where
+    T: TryFrom<ParameterValue>,
+{
+    match pv {
+        ParameterValue::Atom(atom) => T::try_from(ParameterValue::Atom(atom))
+            .map(|x| vec![x])
+            .map_err(|_| FromParameterValueError::InvalidElement { index: 1 }),
+        ParameterValue::Array(vmap) => {
+            let mut entries: Vec<_> = vmap.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            entries
+                .into_iter()
+                .enumerate()
+                .map(|(i, (_, atom))| {
+                    T::try_from(ParameterValue::Atom(atom))
+                        .map_err(|_| FromParameterValueError::InvalidElement { index: i + 1 })
+                })
+                .collect()
+        }
+    }
+}
+
+/// A parameter of a namelist, if it was given.
+fn get_parameter<T>(namelist: &Namelist, name: &str) -> Result<Option<T>, DecodeErrorKind>
+where
+    T: TryFrom<ParameterValue>,
+{
+    namelist
+        .parameters
+        .get(name)
+        .cloned()
+        .map(|x| {
+            x.try_into()
+                .map_err(|_| DecodeErrorKind::InvalidParameter(name.to_string()))
+        })
+        .transpose()
+}
+
+/// A parameter of a namelist which is a list of values, if it was given.
+fn get_parameter_vec<T>(namelist: &Namelist, name: &str) -> Result<Option<Vec<T>>, DecodeErrorKind>
+where
+    T: TryFrom<ParameterValue>,
+{
+    namelist
+        .parameters
+        .get(name)
+        .cloned()
+        .map(|x| {
+            parameter_vec(x).map_err(|_| DecodeErrorKind::InvalidParameter(name.to_string()))
+        })
+        .transpose()
+}
+
+/// A parameter of a namelist which must be given.
+fn get_required_parameter<T>(namelist: &Namelist, name: &str) -> Result<T, DecodeErrorKind>
+where
+    T: TryFrom<ParameterValue>,
+{
+    get_parameter(namelist, name)?
+        .ok_or_else(|| DecodeErrorKind::MissingParameter(name.to_string()))
+}
+
 fn decode_obst(fds_file: &mut FDSFile, namelist: &Namelist) {
-    let obst = Obst {
+    let mut obst = Obst {
         //     allow_vent: bool,
         //     bndf_face: (bool, bool, bool, bool, bool, bool),
         //     bndf_obst: bool,
         //     bulk_density: Option<f64>,
         //     color: Option<String>,
-        //     ctrl_id: Option<String>,
-        //     devc_id: Option<String>,
+        ctrl_id: namelist
+            .parameters
+            .get("CTRL_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("obst.ctrl_id")),
+        devc_id: namelist
+            .parameters
+            .get("DEVC_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("obst.devc_id")),
         //     evacuation: bool,
         //     fyi: Option<String>,
         //     ht3d: bool,
@@ -2007,15 +2873,35 @@ fn decode_obst(fds_file: &mut FDSFile, namelist: &Namelist) {
             .get("ID")
             .cloned()
             .map(|x| x.try_into().expect("obst.id")),
-
-        //     matl_id: Option<String>,
-        //     mesh_id: Option<String>,
-        //     mult_id: Option<String>,
+        matl_id: namelist
+            .parameters
+            .get("MATL_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("obst.matl_id")),
+        mesh_id: namelist
+            .parameters
+            .get("MESH_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("obst.mesh_id")),
+        mult_id: namelist
+            .parameters
+            .get("MULT_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("obst.mult_id")),
         //     // , NOTERRAIN : bool
         //     outline: bool,
         //     overlay: bool,
-        //     permit_hole: bool,
-        //     prop_id: Option<String>,
+        permit_hole: namelist
+            .parameters
+            .get("PERMIT_HOLE")
+            .cloned()
+            .map(|x| x.try_into().expect("obst.permit_hole"))
+            .unwrap_or_else(default_obst_permit_hole),
+        prop_id: namelist
+            .parameters
+            .get("PROP_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("obst.prop_id")),
         //     removable: bool,
         //     rgb: Option<RGB>,
         surf_id: namelist
@@ -2023,32 +2909,8 @@ fn decode_obst(fds_file: &mut FDSFile, namelist: &Namelist) {
             .get("SURF_ID")
             .cloned()
             .map(|x| x.try_into().expect("surf_id failed")),
-        surf_id6: namelist.parameters.get("SURF_ID6").map(|pv| match pv {
-            ParameterValue::Atom(s) => panic!("expected array"),
-            ParameterValue::Array(vmap) => match vmap.len() {
-                6 => (
-                    ParameterValue::Atom(vmap.get(&vec![1]).unwrap().clone())
-                        .try_into()
-                        .unwrap(),
-                    ParameterValue::Atom(vmap.get(&vec![2]).unwrap().clone())
-                        .try_into()
-                        .unwrap(),
-                    ParameterValue::Atom(vmap.get(&vec![3]).unwrap().clone())
-                        .try_into()
-                        .unwrap(),
-                    ParameterValue::Atom(vmap.get(&vec![4]).unwrap().clone())
-                        .try_into()
-                        .unwrap(),
-                    ParameterValue::Atom(vmap.get(&vec![5]).unwrap().clone())
-                        .try_into()
-                        .unwrap(),
-                    ParameterValue::Atom(vmap.get(&vec![6]).unwrap().clone())
-                        .try_into()
-                        .unwrap(),
-                ),
-                l => panic!("expected array of lengh 6, not {}", l),
-            },
-        }),
+        // Filled in below, as faces it leaves out depend on the other fields.
+        surf_id6: None,
         surf_ids: namelist.parameters.get("SURF_IDS").map(|pv| match pv {
             ParameterValue::Atom(s) => panic!("expected array"),
             ParameterValue::Array(vmap) => match vmap.len() {
@@ -2067,7 +2929,12 @@ fn decode_obst(fds_file: &mut FDSFile, namelist: &Namelist) {
             },
         }),
         //     texture_origin: XYZ,
-        //     thicken: bool,
+        thicken: namelist
+            .parameters
+            .get("THICKEN")
+            .cloned()
+            .map(|x| x.try_into().expect("obst.thicken"))
+            .unwrap_or_else(default_obst_thicken),
         //     transparency: f64,
         xb: namelist
             .parameters
@@ -2076,6 +2943,25 @@ fn decode_obst(fds_file: &mut FDSFile, namelist: &Namelist) {
             .map(|x| x.try_into().expect("obst.xb"))
             .unwrap(),
     };
+    if let Some(pv) = namelist.parameters.get("SURF_ID6") {
+        // As in FDS, faces which SURF_ID6 leaves out take the SURF given by
+        // SURF_IDS or SURF_ID.
+        let face = |index: usize| -> String {
+            let element = match pv {
+                ParameterValue::Atom(atom) if index == 1 => Some(ParameterValue::Atom(atom.clone())),
+                ParameterValue::Atom(_) => None,
+                ParameterValue::Array(vmap) => vmap
+                    .get(&vec![index as _])
+                    .cloned()
+                    .map(ParameterValue::Atom),
+            };
+            match element {
+                Some(element) => element.try_into().expect("obst.surf_id6"),
+                None => obst.surf_for_face(Direction::ALL[index - 1]).to_string(),
+            }
+        };
+        obst.surf_id6 = Some((face(1), face(2), face(3), face(4), face(5), face(6)));
+    }
     fds_file.obsts.push(obst);
 }
 
@@ -2086,8 +2972,16 @@ fn decode_vent(fds_file: &mut FDSFile, namelist: &Namelist) {
         //     bndf_obst: bool,
         //     bulk_density: Option<f64>,
         //     color: Option<String>,
-        //     ctrl_id: Option<String>,
-        //     devc_id: Option<String>,
+        ctrl_id: namelist
+            .parameters
+            .get("CTRL_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("vent.ctrl_id")),
+        devc_id: namelist
+            .parameters
+            .get("DEVC_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("vent.devc_id")),
         //     evacuation: bool,
         //     fyi: Option<String>,
         //     ht3d: bool,
@@ -2097,8 +2991,16 @@ fn decode_vent(fds_file: &mut FDSFile, namelist: &Namelist) {
             .cloned()
             .map(|x| x.try_into().expect("vent.id")),
         //     matl_id: Option<String>,
-        //     mesh_id: Option<String>,
-        //     mult_id: Option<String>,
+        mesh_id: namelist
+            .parameters
+            .get("MESH_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("vent.mesh_id")),
+        mult_id: namelist
+            .parameters
+            .get("MULT_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("vent.mult_id")),
         //     // , NOTERRAIN : bool
         //     outline: bool,
         //     overlay: bool,
@@ -2125,16 +3027,46 @@ fn decode_vent(fds_file: &mut FDSFile, namelist: &Namelist) {
 
 fn decode_devc(fds_file: &mut FDSFile, namelist: &Namelist) {
     let devc = Devc {
+        ctrl_id: namelist
+            .parameters
+            .get("CTRL_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("devc.ctrl_id")),
+        devc_id: namelist
+            .parameters
+            .get("DEVC_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("devc.devc_id")),
         id: namelist
             .parameters
             .get("ID")
             .cloned()
             .map(|x| x.try_into().expect("devc.id")),
+        matl_id: namelist
+            .parameters
+            .get("MATL_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("devc.matl_id")),
+        part_id: namelist
+            .parameters
+            .get("PART_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("devc.part_id")),
         prop_id: namelist
             .parameters
             .get("PROP_ID")
             .cloned()
             .map(|x| x.try_into().expect("devc.prop_id")),
+        quantity: namelist
+            .parameters
+            .get("QUANTITY")
+            .cloned()
+            .map(|x| x.try_into().expect("devc.quantity")),
+        spec_id: namelist
+            .parameters
+            .get("SPEC_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("devc.spec_id")),
         xyz: namelist
             .parameters
             .get("XYZ")
@@ -2185,6 +3117,413 @@ fn decode_prop(fds_file: &mut FDSFile, namelist: &Namelist) {
     fds_file.props.push(prop);
 }
 
+fn decode_hole(fds_file: &mut FDSFile, namelist: &Namelist) -> Result<(), DecodeErrorKind> {
+    let hole = Hole {
+        color: get_parameter(namelist, "COLOR")?,
+        ctrl_id: get_parameter(namelist, "CTRL_ID")?,
+        devc_id: get_parameter(namelist, "DEVC_ID")?,
+        evacuation: get_parameter(namelist, "EVACUATION")?,
+        fyi: get_parameter(namelist, "FYI")?,
+        id: get_parameter(namelist, "ID")?,
+        mesh_id: get_parameter(namelist, "MESH_ID")?,
+        mult_id: get_parameter(namelist, "MULT_ID")?,
+        rgb: get_parameter(namelist, "RGB")?,
+        transparency: get_parameter(namelist, "TRANSPARENCY")?
+            .unwrap_or_else(|| catalog::default_real("HOLE", "TRANSPARENCY")),
+        xb: get_required_parameter(namelist, "XB")?,
+    };
+    fds_file.holes.push(hole);
+    Ok(())
+}
+
+fn decode_ctrl(fds_file: &mut FDSFile, namelist: &Namelist) -> Result<(), DecodeErrorKind> {
+    let ctrl = Ctrl {
+        constant: get_parameter(namelist, "CONSTANT")?,
+        delay: get_parameter(namelist, "DELAY")?
+            .unwrap_or_else(|| catalog::default_real("CTRL", "DELAY")),
+        differential_gain: get_parameter(namelist, "DIFFERENTIAL_GAIN")?
+            .unwrap_or_else(|| catalog::default_real("CTRL", "DIFFERENTIAL_GAIN")),
+        evacuation: get_parameter(namelist, "EVACUATION")?,
+        function_type: get_parameter(namelist, "FUNCTION_TYPE")?,
+        id: get_parameter(namelist, "ID")?,
+        initial_state: get_parameter(namelist, "INITIAL_STATE")?
+            .unwrap_or_else(|| catalog::default_logical("CTRL", "INITIAL_STATE")),
+        integral_gain: get_parameter(namelist, "INTEGRAL_GAIN")?
+            .unwrap_or_else(|| catalog::default_real("CTRL", "INTEGRAL_GAIN")),
+        input_id: get_parameter_vec(namelist, "INPUT_ID")?.unwrap_or_default(),
+        latch: get_parameter(namelist, "LATCH")?
+            .unwrap_or_else(|| catalog::default_logical("CTRL", "LATCH")),
+        n: get_parameter(namelist, "N")?.unwrap_or_else(|| catalog::default_integer("CTRL", "N")),
+        on_bound: get_parameter(namelist, "ON_BOUND")?
+            .unwrap_or_else(|| catalog::default_character("CTRL", "ON_BOUND").to_string()),
+        proportional_gain: get_parameter(namelist, "PROPORTIONAL_GAIN")?
+            .unwrap_or_else(|| catalog::default_real("CTRL", "PROPORTIONAL_GAIN")),
+        ramp_id: get_parameter(namelist, "RAMP_ID")?,
+        setpoint: get_parameter(namelist, "SETPOINT")?,
+        target_value: get_parameter(namelist, "TARGET_VALUE")?
+            .unwrap_or_else(|| catalog::default_real("CTRL", "TARGET_VALUE")),
+        trip_direction: get_parameter(namelist, "TRIP_DIRECTION")?
+            .unwrap_or_else(|| catalog::default_integer("CTRL", "TRIP_DIRECTION")),
+    };
+    fds_file.ctrls.push(ctrl);
+    Ok(())
+}
+
+fn decode_matl(fds_file: &mut FDSFile, namelist: &Namelist) -> Result<(), DecodeErrorKind> {
+    let matl = Matl {
+        a: get_parameter_vec(namelist, "A")?.unwrap_or_default(),
+        absorption_coefficient: get_parameter(namelist, "ABSORPTION_COEFFICIENT")?
+            .unwrap_or_else(|| catalog::default_real("MATL", "ABSORPTION_COEFFICIENT")),
+        boiling_temperature: get_parameter(namelist, "BOILING_TEMPERATURE")?,
+        color: get_parameter(namelist, "COLOR")?,
+        conductivity: get_parameter(namelist, "CONDUCTIVITY")?,
+        conductivity_ramp: get_parameter(namelist, "CONDUCTIVITY_RAMP")?,
+        density: get_parameter(namelist, "DENSITY")?,
+        e: get_parameter_vec(namelist, "E")?.unwrap_or_default(),
+        emissivity: get_parameter(namelist, "EMISSIVITY")?
+            .unwrap_or_else(|| catalog::default_real("MATL", "EMISSIVITY")),
+        fyi: get_parameter(namelist, "FYI")?,
+        heating_rate: get_parameter_vec(namelist, "HEATING_RATE")?
+            .unwrap_or_else(|| catalog::default_reals("MATL", "HEATING_RATE").to_vec()),
+        heat_of_combustion: get_parameter_vec(namelist, "HEAT_OF_COMBUSTION")?.unwrap_or_default(),
+        heat_of_reaction: get_parameter_vec(namelist, "HEAT_OF_REACTION")?.unwrap_or_default(),
+        id: get_parameter(namelist, "ID")?,
+        matl_id: get_parameter_vec(namelist, "MATL_ID")?.unwrap_or_default(),
+        nu_matl: get_parameter_vec(namelist, "NU_MATL")?.unwrap_or_default(),
+        nu_spec: get_parameter_vec(namelist, "NU_SPEC")?.unwrap_or_default(),
+        n_reactions: get_parameter(namelist, "N_REACTIONS")?,
+        n_s: get_parameter_vec(namelist, "N_S")?.unwrap_or_default(),
+        n_t: get_parameter_vec(namelist, "N_T")?.unwrap_or_default(),
+        n_o2: get_parameter_vec(namelist, "N_O2")?.unwrap_or_default(),
+        pcr: get_parameter_vec(namelist, "PCR")?.unwrap_or_default(),
+        pyrolysis_range: get_parameter_vec(namelist, "PYROLYSIS_RANGE")?
+            .unwrap_or_else(|| catalog::default_reals("MATL", "PYROLYSIS_RANGE").to_vec()),
+        reference_rate: get_parameter_vec(namelist, "REFERENCE_RATE")?.unwrap_or_default(),
+        reference_temperature: get_parameter_vec(namelist, "REFERENCE_TEMPERATURE")?
+            .unwrap_or_default(),
+        rgb: get_parameter(namelist, "RGB")?,
+        specific_heat: get_parameter(namelist, "SPECIFIC_HEAT")?,
+        specific_heat_ramp: get_parameter(namelist, "SPECIFIC_HEAT_RAMP")?,
+        spec_id: get_parameter_vec(namelist, "SPEC_ID")?.unwrap_or_default(),
+        threshold_sign: get_parameter_vec(namelist, "THRESHOLD_SIGN")?.unwrap_or_default(),
+        threshold_temperature: get_parameter_vec(namelist, "THRESHOLD_TEMPERATURE")?
+            .unwrap_or_default(),
+        allow_shrinking: get_parameter(namelist, "ALLOW_SHRINKING")?,
+        allow_swelling: get_parameter(namelist, "ALLOW_SWELLING")?,
+        gas_diffusion_depth: get_parameter_vec(namelist, "GAS_DIFFUSION_DEPTH")?
+            .unwrap_or_default(),
+    };
+    fds_file.matls.push(matl);
+    Ok(())
+}
+
+fn decode_spec(fds_file: &mut FDSFile, namelist: &Namelist) -> Result<(), DecodeErrorKind> {
+    let spec = Spec {
+        aerosol: get_parameter(namelist, "AEROSOL")?
+            .unwrap_or_else(|| catalog::default_logical("SPEC", "AEROSOL")),
+        alias: get_parameter(namelist, "ALIAS")?,
+        background: get_parameter(namelist, "BACKGROUND")?
+            .unwrap_or_else(|| catalog::default_logical("SPEC", "BACKGROUND")),
+        conductivity: get_parameter(namelist, "CONDUCTIVITY")?,
+        conductivity_solid: get_parameter(namelist, "CONDUCTIVITY_SOLID")?,
+        density_liquid: get_parameter(namelist, "DENSITY_LIQUID")?,
+        density_solid: get_parameter(namelist, "DENSITY_SOLID")?,
+        diffusivity: get_parameter(namelist, "DIFFUSIVITY")?,
+        enthalpy_of_formation: get_parameter(namelist, "ENTHALPY_OF_FORMATION")?,
+        epsilonklj: get_parameter(namelist, "EPSILONKLJ")?,
+        fic_concentration: get_parameter(namelist, "FIC_CONCENTRATION")?,
+        fld_lethal_dose: get_parameter(namelist, "FLD_LETHAL_DOSE")?,
+        formula: get_parameter(namelist, "FORMULA")?,
+        fyi: get_parameter(namelist, "FYI")?,
+        heat_of_vaporization: get_parameter(namelist, "HEAT_OF_VAPORIZATION")?,
+        h_v_reference_temperature: get_parameter(namelist, "H_V_REFERENCE_TEMPERATURE")?,
+        id: get_parameter(namelist, "ID")?,
+        lumped_component_only: get_parameter(namelist, "LUMPED_COMPONENT_ONLY")?,
+        mass_extinction_coefficient: get_parameter(namelist, "MASS_EXTINCTION_COEFFICIENT")?,
+        mass_fraction: get_parameter_vec(namelist, "MASS_FRACTION")?.unwrap_or_default(),
+        mass_fraction_0: get_parameter(namelist, "MASS_FRACTION_0")?,
+        mean_diameter: get_parameter(namelist, "MEAN_DIAMETER")?,
+        melting_temperature: get_parameter(namelist, "MELTING_TEMPERATURE")?,
+        mw: get_parameter(namelist, "MW")?,
+        pr_gas: get_parameter(namelist, "PR_GAS")?,
+        primitive: get_parameter(namelist, "PRIMITIVE")?,
+        radcal_id: get_parameter(namelist, "RADCAL_ID")?,
+        ramp_cp: get_parameter(namelist, "RAMP_CP")?,
+        ramp_cp_l: get_parameter(namelist, "RAMP_CP_L")?,
+        ramp_d: get_parameter(namelist, "RAMP_D")?,
+        ramp_g_f: get_parameter(namelist, "RAMP_G_F")?,
+        ramp_k: get_parameter(namelist, "RAMP_K")?,
+        ramp_mu: get_parameter(namelist, "RAMP_MU")?,
+        reference_enthalpy: get_parameter(namelist, "REFERENCE_ENTHALPY")?,
+        reference_temperature: get_parameter(namelist, "REFERENCE_TEMPERATURE")?,
+        sigmalj: get_parameter(namelist, "SIGMALJ")?,
+        spec_id: get_parameter_vec(namelist, "SPEC_ID")?.unwrap_or_default(),
+        specific_heat: get_parameter(namelist, "SPECIFIC_HEAT")?,
+        specific_heat_liquid: get_parameter(namelist, "SPECIFIC_HEAT_LIQUID")?,
+        vaporization_temperature: get_parameter(namelist, "VAPORIZATION_TEMPERATURE")?,
+        viscosity: get_parameter(namelist, "VISCOSITY")?,
+        volume_fraction: get_parameter_vec(namelist, "VOLUME_FRACTION")?.unwrap_or_default(),
+    };
+    fds_file.specs.push(spec);
+    Ok(())
+}
+
+fn decode_part(fds_file: &mut FDSFile, namelist: &Namelist) -> Result<(), DecodeErrorKind> {
+    let part = Part {
+        age: get_parameter(namelist, "AGE")?,
+        breakup: get_parameter(namelist, "BREAKUP")?,
+        breakup_cnf_ramp_id: get_parameter(namelist, "BREAKUP_CNF_RAMP_ID")?,
+        breakup_distribution: get_parameter(namelist, "BREAKUP_DISTRIBUTION")?,
+        breakup_gamma_d: get_parameter(namelist, "BREAKUP_GAMMA_D")?,
+        breakup_ratio: get_parameter(namelist, "BREAKUP_RATIO")?,
+        breakup_sigma_d: get_parameter(namelist, "BREAKUP_SIGMA_D")?,
+        check_distribution: get_parameter(namelist, "CHECK_DISTRIBUTION")?,
+        cnf_ramp_id: get_parameter(namelist, "CNF_RAMP_ID")?,
+        color: get_parameter(namelist, "COLOR")?,
+        complex_refractive_index: get_parameter(namelist, "COMPLEX_REFRACTIVE_INDEX")?,
+        ctrl_id: get_parameter(namelist, "CTRL_ID")?,
+        dense_volume_fraction: get_parameter(namelist, "DENSE_VOLUME_FRACTION")?,
+        devc_id: get_parameter(namelist, "DEVC_ID")?,
+        diameter: get_parameter(namelist, "DIAMETER")?,
+        distribution: get_parameter(namelist, "DISTRIBUTION")?,
+        drag_coefficient: get_parameter_vec(namelist, "DRAG_COEFFICIENT")?.unwrap_or_default(),
+        drag_law: get_parameter(namelist, "DRAG_LAW")?,
+        free_area_fraction: get_parameter(namelist, "FREE_AREA_FRACTION")?,
+        fyi: get_parameter(namelist, "FYI")?,
+        gamma_d: get_parameter(namelist, "GAMMA_D")?,
+        heat_of_combustion: get_parameter(namelist, "HEAT_OF_COMBUSTION")?,
+        horizontal_velocity: get_parameter(namelist, "HORIZONTAL_VELOCITY")?,
+        id: get_parameter(namelist, "ID")?,
+        initial_temperature: get_parameter(namelist, "INITIAL_TEMPERATURE")?,
+        massless: get_parameter(namelist, "MASSLESS")?
+            .unwrap_or_else(|| catalog::default_logical("PART", "MASSLESS")),
+        maximum_diameter: get_parameter(namelist, "MAXIMUM_DIAMETER")?,
+        minimum_diameter: get_parameter(namelist, "MINIMUM_DIAMETER")?,
+        monodisperse: get_parameter(namelist, "MONODISPERSE")?
+            .unwrap_or_else(|| catalog::default_logical("PART", "MONODISPERSE")),
+        n_strata: get_parameter(namelist, "N_STRATA")?,
+        orientation: get_parameter_vec(namelist, "ORIENTATION")?.unwrap_or_default(),
+        permeability: get_parameter_vec(namelist, "PERMEABILITY")?.unwrap_or_default(),
+        periodic_x: get_parameter(namelist, "PERIODIC_X")?,
+        periodic_y: get_parameter(namelist, "PERIODIC_Y")?,
+        periodic_z: get_parameter(namelist, "PERIODIC_Z")?,
+        porous_volume_fraction: get_parameter(namelist, "POROUS_VOLUME_FRACTION")?,
+        prop_id: get_parameter(namelist, "PROP_ID")?,
+        quantities: get_parameter_vec(namelist, "QUANTITIES")?.unwrap_or_default(),
+        quantities_spec_id: get_parameter_vec(namelist, "QUANTITIES_SPEC_ID")?.unwrap_or_default(),
+        radiative_property_table: get_parameter(namelist, "RADIATIVE_PROPERTY_TABLE")?,
+        real_refractive_index: get_parameter(namelist, "REAL_REFRACTIVE_INDEX")?,
+        rgb: get_parameter(namelist, "RGB")?,
+        running_average_factor: get_parameter(namelist, "RUNNING_AVERAGE_FACTOR")?,
+        sampling_factor: get_parameter(namelist, "SAMPLING_FACTOR")?
+            .unwrap_or_else(|| catalog::default_integer("PART", "SAMPLING_FACTOR")),
+        second_order_particle_transport: get_parameter(namelist, "SECOND_ORDER_PARTICLE_TRANSPORT")?,
+        sigma_d: get_parameter(namelist, "SIGMA_D")?,
+        spec_id: get_parameter(namelist, "SPEC_ID")?,
+        static_: get_parameter(namelist, "STATIC")?
+            .unwrap_or_else(|| catalog::default_logical("PART", "STATIC")),
+        surface_tension: get_parameter(namelist, "SURFACE_TENSION")?,
+        surf_id: get_parameter(namelist, "SURF_ID")?,
+        target_only: get_parameter(namelist, "TARGET_ONLY")?,
+        turbulent_dispersion: get_parameter(namelist, "TURBULENT_DISPERSION")?,
+        vertical_velocity: get_parameter(namelist, "VERTICAL_VELOCITY")?,
+    };
+    fds_file.parts.push(part);
+    Ok(())
+}
+
+fn decode_mult(fds_file: &mut FDSFile, namelist: &Namelist) -> Result<(), DecodeErrorKind> {
+    let mult = Mult {
+        dx: get_parameter(namelist, "DX")?.unwrap_or_else(|| catalog::default_real("MULT", "DX")),
+        dxb: get_parameter_vec(namelist, "DXB")?.unwrap_or_default(),
+        dx0: get_parameter(namelist, "DX0")?
+            .unwrap_or_else(|| catalog::default_real("MULT", "DX0")),
+        dy: get_parameter(namelist, "DY")?.unwrap_or_else(|| catalog::default_real("MULT", "DY")),
+        dyb: get_parameter_vec(namelist, "DYB")?.unwrap_or_default(),
+        dy0: get_parameter(namelist, "DY0")?
+            .unwrap_or_else(|| catalog::default_real("MULT", "DY0")),
+        dz: get_parameter(namelist, "DZ")?.unwrap_or_else(|| catalog::default_real("MULT", "DZ")),
+        dzb: get_parameter_vec(namelist, "DZB")?.unwrap_or_default(),
+        dz0: get_parameter(namelist, "DZ0")?
+            .unwrap_or_else(|| catalog::default_real("MULT", "DZ0")),
+        id: get_parameter(namelist, "ID")?,
+        i_lower: get_parameter(namelist, "I_LOWER")?
+            .unwrap_or_else(|| catalog::default_integer("MULT", "I_LOWER")),
+        i_upper: get_parameter(namelist, "I_UPPER")?
+            .unwrap_or_else(|| catalog::default_integer("MULT", "I_UPPER")),
+        j_lower: get_parameter(namelist, "J_LOWER")?
+            .unwrap_or_else(|| catalog::default_integer("MULT", "J_LOWER")),
+        j_upper: get_parameter(namelist, "J_UPPER")?
+            .unwrap_or_else(|| catalog::default_integer("MULT", "J_UPPER")),
+        k_lower: get_parameter(namelist, "K_LOWER")?
+            .unwrap_or_else(|| catalog::default_integer("MULT", "K_LOWER")),
+        k_upper: get_parameter(namelist, "K_UPPER")?
+            .unwrap_or_else(|| catalog::default_integer("MULT", "K_UPPER")),
+        n_lower: get_parameter(namelist, "N_LOWER")?
+            .unwrap_or_else(|| catalog::default_integer("MULT", "N_LOWER")),
+        n_upper: get_parameter(namelist, "N_UPPER")?
+            .unwrap_or_else(|| catalog::default_integer("MULT", "N_UPPER")),
+    };
+    fds_file.mults.push(mult);
+    Ok(())
+}
+
+/// RAMP namelists with the same ID are collected into a single [`Ramp`], in
+/// the order they appear.
+fn decode_ramp(fds_file: &mut FDSFile, namelist: &Namelist) -> Result<(), DecodeErrorKind> {
+    let id: String = get_required_parameter(namelist, "ID")?;
+    let entry = RampEntry {
+        ctrl_id: get_parameter(namelist, "CTRL_ID")?,
+        devc_id: get_parameter(namelist, "DEVC_ID")?,
+        f: get_required_parameter(namelist, "F")?,
+        fyi: get_parameter(namelist, "FYI")?,
+        number_interpolation_points: get_parameter(namelist, "NUMBER_INTERPOLATION_POINTS")?
+            .unwrap_or_else(default_ramp_number_interpolation_points),
+        t: get_parameter(namelist, "T")?,
+        x: get_parameter(namelist, "X")?,
+        z: get_parameter(namelist, "Z")?,
+    };
+    match fds_file.ramps.iter_mut().find(|ramp| ramp.id == id) {
+        Some(ramp) => ramp.entries.push(entry),
+        None => fds_file.ramps.push(Ramp {
+            id,
+            entries: vec![entry],
+        }),
+    }
+    Ok(())
+}
+
+fn decode_slcf(fds_file: &mut FDSFile, namelist: &Namelist) {
+    let slcf = Slcf {
+        agl_slice: namelist
+            .parameters
+            .get("AGL_SLICE")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.agl_slice")),
+        cell_centered: namelist
+            .parameters
+            .get("CELL_CENTERED")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.cell_centered"))
+            .unwrap_or_else(|| catalog::default_logical("SLCF", "CELL_CENTERED")),
+        evacuation: namelist
+            .parameters
+            .get("EVACUATION")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.evacuation")),
+        fyi: namelist
+            .parameters
+            .get("FYI")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.fyi")),
+        id: namelist
+            .parameters
+            .get("ID")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.id")),
+        ior: namelist
+            .parameters
+            .get("IOR")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.ior")),
+        level_set_fire_line: namelist
+            .parameters
+            .get("LEVEL_SET_FIRE_LINE")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.level_set_fire_line")),
+        maximum_value: namelist
+            .parameters
+            .get("MAXIMUM_VALUE")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.maximum_value")),
+        mesh_number: namelist
+            .parameters
+            .get("MESH_NUMBER")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.mesh_number")),
+        minimum_value: namelist
+            .parameters
+            .get("MINIMUM_VALUE")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.minimum_value")),
+        part_id: namelist
+            .parameters
+            .get("PART_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.part_id")),
+        pbx: namelist
+            .parameters
+            .get("PBX")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.pbx")),
+        pby: namelist
+            .parameters
+            .get("PBY")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.pby")),
+        pbz: namelist
+            .parameters
+            .get("PBZ")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.pbz")),
+        quantity: namelist
+            .parameters
+            .get("QUANTITY")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.quantity")),
+        quantity2: namelist
+            .parameters
+            .get("QUANTITY2")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.quantity2")),
+        reac_id: namelist
+            .parameters
+            .get("REAC_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.reac_id")),
+        spec_id: namelist
+            .parameters
+            .get("SPEC_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.spec_id")),
+        vector: namelist
+            .parameters
+            .get("VECTOR")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.vector"))
+            .unwrap_or_else(|| catalog::default_logical("SLCF", "VECTOR")),
+        velo_index: namelist
+            .parameters
+            .get("VELO_INDEX")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.velo_index"))
+            .unwrap_or_else(|| catalog::default_integer("SLCF", "VELO_INDEX")),
+        xb: namelist
+            .parameters
+            .get("XB")
+            .cloned()
+            .map(|x| x.try_into().expect("slcf.xb")),
+    };
+    fds_file.slcfs.push(slcf);
+}
+
+fn decode_misc(fds_file: &mut FDSFile, namelist: &Namelist) -> Result<(), DecodeErrorKind> {
+    if fds_file.misc.is_some() {
+        return Err(DecodeErrorKind::Repeated);
+    }
+    let default = Misc::default();
+    let misc = Misc {
+        maximum_visibility: get_parameter(namelist, "MAXIMUM_VISIBILITY")?
+            .unwrap_or(default.maximum_visibility),
+        p_inf: get_parameter(namelist, "P_INF")?.unwrap_or(default.p_inf),
+        tmpa: get_parameter(namelist, "TMPA")?.unwrap_or(default.tmpa),
+        visibility_factor: get_parameter(namelist, "VISIBILITY_FACTOR")?
+            .unwrap_or(default.visibility_factor),
+    };
+    fds_file.misc = Some(misc);
+    Ok(())
+}
+
 fn decode_head(fds_file: &mut FDSFile, namelist: &Namelist) {
     let head = Head {
         chid: namelist
@@ -2213,13 +3552,13 @@ fn decode_surf(fds_file: &mut FDSFile, namelist: &Namelist) {
             .get("ADIABATIC")
             .cloned()
             .map(|x| x.try_into().expect("adiabatic"))
-            .unwrap_or(false),
+            .unwrap_or_else(|| catalog::default_logical("SURF", "ADIABATIC")),
         auto_ignition_temperature: namelist
             .parameters
             .get("AUTO_IGNITION_TEMPERATURE")
             .cloned()
             .map(|x| x.try_into().expect("auto_ignition_tmperature"))
-            .unwrap_or(-273_f64),
+            .unwrap_or_else(|| catalog::default_real("SURF", "AUTO_IGNITION_TEMPERATURE")),
         color: namelist
             .parameters
             .get("COLOR")
@@ -2240,11 +3579,43 @@ fn decode_surf(fds_file: &mut FDSFile, namelist: &Namelist) {
             .get("ID")
             .cloned()
             .map(|x| x.try_into().expect("id")),
+        matl_id: namelist
+            .parameters
+            .get("MATL_ID")
+            .cloned()
+            .map(|x| parameter_vec(x).expect("surf.matl_id"))
+            .unwrap_or_default(),
         mlrpua: namelist
             .parameters
             .get("MLRPUA")
             .cloned()
             .map(|x| x.try_into().expect("mlrpua")),
+        part_id: namelist
+            .parameters
+            .get("PART_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("part_id")),
+        ramp_q: namelist
+            .parameters
+            .get("RAMP_Q")
+            .cloned()
+            .map(|x| x.try_into().expect("ramp_q")),
+        ramp_t: namelist
+            .parameters
+            .get("RAMP_T")
+            .cloned()
+            .map(|x| x.try_into().expect("ramp_t")),
+        ramp_v: namelist
+            .parameters
+            .get("RAMP_V")
+            .cloned()
+            .map(|x| x.try_into().expect("ramp_v")),
+        spec_id: namelist
+            .parameters
+            .get("SPEC_ID")
+            .cloned()
+            .map(|x| parameter_vec(x).expect("surf.spec_id"))
+            .unwrap_or_default(),
         vel: namelist
             .parameters
             .get("VEL")
@@ -2420,6 +3791,7 @@ fn decode_surf(fds_file: &mut FDSFile, namelist: &Namelist) {
 //     in fdsData { Devcs = devc:(Devcs fdsData)}
 
 fn decode_mesh(fds_file: &mut FDSFile, namelist: &Namelist) {
+    let default = Mesh::default();
     let mesh = Mesh {
         id: namelist
             .parameters
@@ -2431,13 +3803,61 @@ fn decode_mesh(fds_file: &mut FDSFile, namelist: &Namelist) {
             .get("IJK")
             .cloned()
             .map(|x| x.try_into().expect("mesh.ijk"))
-            .unwrap(),
+            .unwrap_or(default.ijk),
         xb: namelist
             .parameters
             .get("XB")
             .cloned()
             .map(|x| x.try_into().expect("mesh.xb"))
-            .unwrap(),
+            .unwrap_or(default.xb),
+        check_mesh_alignment: namelist
+            .parameters
+            .get("CHECK_MESH_ALIGNMENT")
+            .cloned()
+            .map(|x| x.try_into().expect("mesh.check_mesh_alignment"))
+            .unwrap_or(default.check_mesh_alignment),
+        color: namelist
+            .parameters
+            .get("COLOR")
+            .cloned()
+            .map(|x| x.try_into().expect("mesh.color")),
+        cylindrical: namelist
+            .parameters
+            .get("CYLINDRICAL")
+            .cloned()
+            .map(|x| x.try_into().expect("mesh.cylindrical"))
+            .unwrap_or(default.cylindrical),
+        fyi: namelist
+            .parameters
+            .get("FYI")
+            .cloned()
+            .map(|x| x.try_into().expect("mesh.fyi")),
+        level: namelist
+            .parameters
+            .get("LEVEL")
+            .cloned()
+            .map(|x| x.try_into().expect("mesh.level"))
+            .unwrap_or(default.level),
+        mpi_process: namelist
+            .parameters
+            .get("MPI_PROCESS")
+            .cloned()
+            .map(|x| x.try_into().expect("mesh.mpi_process")),
+        mult_id: namelist
+            .parameters
+            .get("MULT_ID")
+            .cloned()
+            .map(|x| x.try_into().expect("mesh.mult_id")),
+        rgb: namelist
+            .parameters
+            .get("RGB")
+            .cloned()
+            .map(|x| x.try_into().expect("mesh.rgb")),
+        n_threads: namelist
+            .parameters
+            .get("N_THREADS")
+            .cloned()
+            .map(|x| x.try_into().expect("mesh.n_threads")),
     };
     fds_file.meshes.push(mesh);
 }
@@ -2465,6 +3885,45 @@ fn decode_mesh(fds_file: &mut FDSFile, namelist: &Namelist) {
 //             }
 //     in fdsData { Meshes = mesh:(Meshes fdsData)}
 
+fn decode_trnx(fds_file: &mut FDSFile, namelist: &Namelist) -> Result<(), DecodeErrorKind> {
+    let trn = Trnx {
+        cc: get_required_parameter(namelist, "CC")?,
+        fyi: get_parameter(namelist, "FYI")?,
+        ideriv: get_parameter(namelist, "IDERIV")?.unwrap_or_else(default_trnx_ideriv),
+        mesh_number: get_parameter(namelist, "MESH_NUMBER")?
+            .unwrap_or_else(default_trnx_mesh_number),
+        pc: get_required_parameter(namelist, "PC")?,
+    };
+    fds_file.trnxs.push(trn);
+    Ok(())
+}
+
+fn decode_trny(fds_file: &mut FDSFile, namelist: &Namelist) -> Result<(), DecodeErrorKind> {
+    let trn = Trny {
+        cc: get_required_parameter(namelist, "CC")?,
+        fyi: get_parameter(namelist, "FYI")?,
+        ideriv: get_parameter(namelist, "IDERIV")?.unwrap_or_else(default_trny_ideriv),
+        mesh_number: get_parameter(namelist, "MESH_NUMBER")?
+            .unwrap_or_else(default_trny_mesh_number),
+        pc: get_required_parameter(namelist, "PC")?,
+    };
+    fds_file.trnys.push(trn);
+    Ok(())
+}
+
+fn decode_trnz(fds_file: &mut FDSFile, namelist: &Namelist) -> Result<(), DecodeErrorKind> {
+    let trn = Trnz {
+        cc: get_required_parameter(namelist, "CC")?,
+        fyi: get_parameter(namelist, "FYI")?,
+        ideriv: get_parameter(namelist, "IDERIV")?.unwrap_or_else(default_trnz_ideriv),
+        mesh_number: get_parameter(namelist, "MESH_NUMBER")?
+            .unwrap_or_else(default_trnz_mesh_number),
+        pc: get_required_parameter(namelist, "PC")?,
+    };
+    fds_file.trnzs.push(trn);
+    Ok(())
+}
+
 // decodePart : FDSFile -> Namelist -> FDSFile
 // decodePart fdsData nml =
 //     let
@@ -2810,6 +4269,23 @@ fn decode_mesh(fds_file: &mut FDSFile, namelist: &Namelist) {
 
 fn decode_reac(fds_file: &mut FDSFile, namelist: &Namelist) {
     let reac = Reac {
+        fuel: namelist
+            .parameters
+            .get("FUEL")
+            .cloned()
+            .map(|x| x.try_into().expect("reac.fuel")),
+        spec_id_nu: namelist
+            .parameters
+            .get("SPEC_ID_NU")
+            .cloned()
+            .map(|x| parameter_vec(x).expect("reac.spec_id_nu"))
+            .unwrap_or_default(),
+        heat_of_combustion: namelist
+            .parameters
+            .get("HEAT_OF_COMBUSTION")
+            .cloned()
+            .map(|x| x.try_into().expect("reac.heat_of_combustion")),
+
         // adiabatic: namelist
         //     .parameters
         //     .get("ADIABATIC")
@@ -0,0 +1,253 @@
+//! Sprinklers and detectors, and rough estimates of when a fire will activate
+//! them from Alpert's ceiling jet correlations.
+use crate::decode::*;
+use crate::validate::ObjectRef;
+
+/// The kinds of activating device FDS models.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum DetectorKind {
+    /// QUANTITY='SPRINKLER LINK TEMPERATURE'.
+    Sprinkler,
+    /// QUANTITY='LINK TEMPERATURE'.
+    HeatDetector,
+    /// QUANTITY='CHAMBER OBSCURATION'.
+    SmokeDetector,
+}
+
+impl DetectorKind {
+    fn from_quantity(quantity: &str) -> Option<Self> {
+        match quantity {
+            "SPRINKLER LINK TEMPERATURE" => Some(DetectorKind::Sprinkler),
+            "LINK TEMPERATURE" => Some(DetectorKind::HeatDetector),
+            "CHAMBER OBSCURATION" => Some(DetectorKind::SmokeDetector),
+            _ => None,
+        }
+    }
+
+    /// The RTI ((m·s)^½) FDS uses when none is given on the PROP.
+    fn default_rti(&self) -> Option<f64> {
+        match self {
+            DetectorKind::Sprinkler => Some(100.0),
+            DetectorKind::HeatDetector => Some(5.0),
+            DetectorKind::SmokeDetector => None,
+        }
+    }
+}
+
+/// The exposure of a detector to one fire.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Exposure {
+    pub fire: ObjectRef,
+    /// The horizontal distance from the centre of the fire (m).
+    pub radial_distance: f64,
+    /// The height of the ceiling above the burning surface (m).
+    pub ceiling_height: f64,
+    /// The estimated time (s) for the peak HRR of the fire to activate the
+    /// detector, or [`None`] if it never would (or, for smoke detectors,
+    /// cannot be estimated).
+    pub activation_time: Option<f64>,
+}
+
+/// A sprinkler or detector.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Detector {
+    pub devc: ObjectRef,
+    pub kind: DetectorKind,
+    pub xyz: XYZ,
+    /// (m·s)^½
+    pub rti: Option<f64>,
+    /// °C
+    pub activation_temperature: Option<f64>,
+    /// %/m
+    pub activation_obscuration: Option<f64>,
+    /// The nearest detector of the same kind, and the distance to it (m).
+    pub nearest: Option<(ObjectRef, f64)>,
+    /// The distance up to the underside of the OBST above the detector, or
+    /// to the top of the mesh if there is none (m).
+    pub distance_to_ceiling: Option<f64>,
+    pub exposures: Vec<Exposure>,
+}
+
+/// Alpert's correlations for the maximum temperature rise (K) and velocity
+/// (m/s) of the ceiling jet from a steady fire of `hrr` (kW), at radial distance
+/// `r` (m) beneath a ceiling `h` (m) above the fire.
+pub fn alpert_ceiling_jet(hrr: f64, h: f64, r: f64) -> (f64, f64) {
+    if r / h <= 0.18 {
+        (
+            16.9 * hrr.powf(2.0 / 3.0) / h.powf(5.0 / 3.0),
+            0.96 * (hrr / h).powf(1.0 / 3.0),
+        )
+    } else {
+        (
+            5.38 * (hrr / r).powf(2.0 / 3.0) / h,
+            0.195 * hrr.powf(1.0 / 3.0) * h.sqrt() / r.powf(5.0 / 6.0),
+        )
+    }
+}
+
+/// The time (s) for a link with `rti` to heat by `activation_rise` (K) in a
+/// gas `gas_rise` (K) above ambient moving at `velocity` (m/s), or [`None`] if
+/// the gas is not hot enough.
+fn link_activation_time(
+    rti: f64,
+    activation_rise: f64,
+    gas_rise: f64,
+    velocity: f64,
+) -> Option<f64> {
+    if gas_rise <= activation_rise {
+        return None;
+    }
+    Some(rti / velocity.sqrt() * (gas_rise / (gas_rise - activation_rise)).ln())
+}
+
+fn distance(a: &XYZ, b: &XYZ) -> f64 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)).sqrt()
+}
+
+impl FDSFile {
+    /// The height of the ceiling above a point: the underside of the lowest
+    /// OBST above it, or the top of the mesh containing it.
+    pub fn ceiling_above(&self, xyz: &XYZ) -> Option<f64> {
+        let obst = self
+            .obsts
+            .iter()
+            .filter(|obst| {
+                obst.xb.x1 <= xyz.x
+                    && xyz.x <= obst.xb.x2
+                    && obst.xb.y1 <= xyz.y
+                    && xyz.y <= obst.xb.y2
+                    && obst.xb.z1 >= xyz.z
+            })
+            .map(|obst| obst.xb.z1)
+            .fold(None, |lowest: Option<f64>, z| {
+                Some(lowest.map_or(z, |l| l.min(z)))
+            });
+        obst.or_else(|| {
+            self.meshes
+                .iter()
+                .find(|mesh| mesh.xb.contains_point(*xyz))
+                .map(|mesh| mesh.xb.z2)
+        })
+    }
+
+    /// Report every sprinkler, heat detector, and smoke detector, with the
+    /// time each burner would take to activate it if burning steadily at its
+    /// peak HRR. Parameters not given on the PROP take the FDS defaults, and
+    /// ambient temperature is from MISC.
+    pub fn detector_report(&self) -> Vec<Detector> {
+        let ambient = self
+            .misc
+            .as_ref()
+            .map_or(Misc::default().tmpa, |misc| misc.tmpa);
+        let fires = self.fires();
+        let mut detectors: Vec<Detector> = Vec::new();
+        for (i, devc) in self.devcs.iter().enumerate() {
+            let xyz = match devc.xyz {
+                Some(xyz) => xyz,
+                None => continue,
+            };
+            let prop = devc.prop_id.as_ref().and_then(|prop_id| {
+                self.props
+                    .iter()
+                    .find(|prop| prop.id.as_ref() == Some(prop_id))
+            });
+            let quantity = devc
+                .quantity
+                .as_deref()
+                .or_else(|| prop.and_then(|prop| prop.quantity.as_deref()));
+            let kind = match quantity.and_then(DetectorKind::from_quantity) {
+                Some(kind) => kind,
+                None => continue,
+            };
+            let rti = prop
+                .and_then(|prop| prop.rti)
+                .or_else(|| kind.default_rti());
+            let activation_temperature = match kind {
+                DetectorKind::SmokeDetector => None,
+                _ => Some(
+                    prop.and_then(|prop| prop.activation_temperature)
+                        .unwrap_or(74.0),
+                ),
+            };
+            let activation_obscuration = match kind {
+                DetectorKind::SmokeDetector => Some(
+                    prop.and_then(|prop| prop.activation_obscuration)
+                        .unwrap_or(3.24),
+                ),
+                _ => None,
+            };
+            let ceiling = self.ceiling_above(&xyz);
+            let exposures = fires
+                .iter()
+                .filter_map(|fire| {
+                    let ceiling = ceiling?;
+                    let centre = fire.xb.center();
+                    let radial_distance =
+                        ((centre.x - xyz.x).powi(2) + (centre.y - xyz.y).powi(2)).sqrt();
+                    let ceiling_height = ceiling - fire.xb.z2;
+                    if ceiling_height <= 0.0 {
+                        return None;
+                    }
+                    let activation_time = match (rti, activation_temperature) {
+                        (Some(rti), Some(activation_temperature)) => {
+                            let (gas_rise, velocity) =
+                                alpert_ceiling_jet(fire.peak_hrr, ceiling_height, radial_distance);
+                            link_activation_time(
+                                rti,
+                                activation_temperature - ambient,
+                                gas_rise,
+                                velocity,
+                            )
+                        }
+                        _ => None,
+                    };
+                    Some(Exposure {
+                        fire: fire.object.clone(),
+                        radial_distance,
+                        ceiling_height,
+                        activation_time,
+                    })
+                })
+                .collect();
+            detectors.push(Detector {
+                devc: ObjectRef::new("DEVC", i, &devc.id),
+                kind,
+                xyz,
+                rti,
+                activation_temperature,
+                activation_obscuration,
+                nearest: None,
+                distance_to_ceiling: ceiling.map(|ceiling| ceiling - xyz.z),
+                exposures,
+            });
+        }
+        let nearest: Vec<Option<(ObjectRef, f64)>> = detectors
+            .iter()
+            .enumerate()
+            .map(|(i, detector)| {
+                detectors
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, other)| j != i && other.kind == detector.kind)
+                    .map(|(_, other)| (other.devc.clone(), distance(&detector.xyz, &other.xyz)))
+                    .fold(
+                        None,
+                        |nearest: Option<(ObjectRef, f64)>, candidate| match nearest {
+                            Some(nearest) if nearest.1 <= candidate.1 => Some(nearest),
+                            _ => Some(candidate),
+                        },
+                    )
+            })
+            .collect();
+        for (detector, nearest) in detectors.iter_mut().zip(nearest) {
+            detector.nearest = nearest;
+        }
+        detectors
+    }
+}
@@ -0,0 +1,213 @@
+//! The fires in a model, and whether the meshes around them are fine enough
+//! to resolve them, following the D*/dx criterion of the FDS User's Guide.
+use crate::decode::*;
+use crate::validate::ObjectRef;
+use crate::xb::MightHaveXB;
+
+/// The specific gas constant of air (J/kg/K).
+const R_AIR: f64 = 287.05;
+
+/// The ambient conditions which set the scale of a fire.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Ambient {
+    /// Temperature (K).
+    pub temperature: f64,
+    /// Density (kg/m³).
+    pub density: f64,
+    /// Specific heat of air (kJ/kg/K).
+    pub specific_heat: f64,
+    /// Acceleration due to gravity (m/s²).
+    pub gravity: f64,
+}
+
+impl Ambient {
+    /// The ambient conditions given by TMPA and P_INF on a MISC namelist, or the
+    /// FDS defaults.
+    pub fn from_misc(misc: Option<&Misc>) -> Self {
+        let default = Misc::default();
+        let misc = misc.unwrap_or(&default);
+        let temperature = misc.tmpa + 273.15;
+        Ambient {
+            temperature,
+            density: misc.p_inf / (R_AIR * temperature),
+            specific_heat: 1.005,
+            gravity: 9.81,
+        }
+    }
+
+    /// The characteristic fire diameter D* (m) of a fire with a heat release
+    /// rate of `hrr` (kW).
+    pub fn characteristic_diameter(&self, hrr: f64) -> f64 {
+        (hrr / (self.density * self.specific_heat * self.temperature * self.gravity.sqrt()))
+            .powf(0.4)
+    }
+}
+
+/// A burner: an OBST or VENT with at least one burning surface.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Fire {
+    pub object: ObjectRef,
+    pub xb: XB,
+    /// The area of the burning surfaces (m²).
+    pub area: f64,
+    /// The peak heat release rate (kW).
+    pub peak_hrr: f64,
+}
+
+/// The resolution of a fire in one of the meshes it lies in.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct FireResolution {
+    pub fire: ObjectRef,
+    pub mesh: ObjectRef,
+    /// The characteristic fire diameter (m).
+    pub d_star: f64,
+    /// The largest side of the cells of the mesh (m).
+    pub cell_size: f64,
+}
+
+impl FireResolution {
+    /// D*/dx, the number of cells across the characteristic diameter of the
+    /// fire. The FDS validation studies cover values from about 4 to 16.
+    pub fn cells_per_diameter(&self) -> f64 {
+        self.d_star / self.cell_size
+    }
+}
+
+impl FDSFile {
+    /// The peak heat release rate per unit area (kW/m²) of a SURF, from
+    /// HRRPUA, or MLRPUA and the HEAT_OF_COMBUSTION of the first REAC, scaled
+    /// by the largest value of RAMP_Q. Returns [`None`] for a SURF which does
+    /// not burn or whose heat release rate cannot be determined.
+    pub fn peak_hrrpua(&self, surf: &Surf) -> Option<f64> {
+        let hrrpua = match (surf.hrrpua, surf.mlrpua) {
+            (Some(hrrpua), _) => hrrpua,
+            (None, Some(mlrpua)) => {
+                mlrpua
+                    * self
+                        .reacs
+                        .first()
+                        .and_then(|reac| reac.heat_of_combustion)?
+            }
+            (None, None) => return None,
+        };
+        let ramp_peak = match &surf.ramp_q {
+            Some(ramp_id) => self
+                .ramps
+                .iter()
+                .find(|ramp| &ramp.id == ramp_id)
+                .map(|ramp| {
+                    ramp.entries
+                        .iter()
+                        .map(|entry| entry.f.abs())
+                        .fold(0.0, f64::max)
+                })
+                .unwrap_or(1.0),
+            None => 1.0,
+        };
+        Some(hrrpua * ramp_peak)
+    }
+
+    /// Find the burning OBSTs and VENTs, and their peak heat release rates.
+    /// For an OBST only the faces with a burning SURF are counted. The areas
+    /// are as specified, not as snapped to the grid. Surfaces given by MLRPUA
+    /// without a HEAT_OF_COMBUSTION are not counted; see
+    /// [`FDSFile::unresolved_fires`].
+    pub fn fires(&self) -> Vec<Fire> {
+        let mut fires = Vec::new();
+        for (i, obst) in self.obsts.iter().enumerate() {
+            let mut area = 0.0;
+            let mut peak_hrr = 0.0;
+            for &direction in Direction::ALL.iter() {
+                let hrrpua = self
+                    .get_surf(obst.surf_for_face(direction))
+                    .and_then(|surf| self.peak_hrrpua(surf));
+                if let Some(hrrpua) = hrrpua {
+                    let face_area = obst.face_area(direction);
+                    area += face_area;
+                    peak_hrr += hrrpua * face_area;
+                }
+            }
+            if peak_hrr > 0.0 {
+                fires.push(Fire {
+                    object: ObjectRef::new("OBST", i, &obst.id),
+                    xb: obst.xb,
+                    area,
+                    peak_hrr,
+                });
+            }
+        }
+        for (i, vent) in self.vents.iter().enumerate() {
+            let hrrpua = vent
+                .surf_id
+                .as_deref()
+                .and_then(|surf_id| self.get_surf(surf_id))
+                .and_then(|surf| self.peak_hrrpua(surf));
+            if let (Some(hrrpua), Some(area), Some(xb)) = (hrrpua, vent.area(), vent.try_xb()) {
+                if hrrpua * area > 0.0 {
+                    fires.push(Fire {
+                        object: ObjectRef::new("VENT", i, &vent.id),
+                        xb,
+                        area,
+                        peak_hrr: hrrpua * area,
+                    });
+                }
+            }
+        }
+        fires
+    }
+
+    /// The OBSTs and VENTs with a SURF which burns by MLRPUA but whose heat
+    /// release rate cannot be determined, because no REAC gives a
+    /// HEAT_OF_COMBUSTION. These are left out of [`FDSFile::fires`].
+    pub fn unresolved_fires(&self) -> Vec<ObjectRef> {
+        let unresolved = |surf: Option<&Surf>| {
+            surf.is_some_and(|surf| surf.mlrpua.is_some() && self.peak_hrrpua(surf).is_none())
+        };
+        let mut objects = Vec::new();
+        for (i, obst) in self.obsts.iter().enumerate() {
+            if Direction::ALL
+                .iter()
+                .any(|&direction| unresolved(self.get_surf(obst.surf_for_face(direction))))
+            {
+                objects.push(ObjectRef::new("OBST", i, &obst.id));
+            }
+        }
+        for (i, vent) in self.vents.iter().enumerate() {
+            let surf = vent
+                .surf_id
+                .as_deref()
+                .and_then(|surf_id| self.get_surf(surf_id));
+            if unresolved(surf) {
+                objects.push(ObjectRef::new("VENT", i, &vent.id));
+            }
+        }
+        objects
+    }
+
+    /// D*/dx for each fire in each mesh it touches, with ambient conditions
+    /// from MISC.
+    pub fn fire_resolution(&self) -> Vec<FireResolution> {
+        let ambient = Ambient::from_misc(self.misc.as_ref());
+        let mut resolutions = Vec::new();
+        for fire in self.fires() {
+            let d_star = ambient.characteristic_diameter(fire.peak_hrr);
+            for (j, mesh) in self.meshes.iter().enumerate() {
+                if mesh.xb.intersection(&fire.xb).is_some() {
+                    resolutions.push(FireResolution {
+                        fire: fire.object.clone(),
+                        mesh: ObjectRef::new("MESH", j, &mesh.id),
+                        d_star,
+                        cell_size: mesh.resolution().max_side(),
+                    });
+                }
+            }
+        }
+        resolutions
+    }
+}
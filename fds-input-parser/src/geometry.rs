@@ -0,0 +1,73 @@
+//! The solid geometry FDS builds from a decoded [`FDSFile`], which is the
+//! OBSTs with any HOLEs cut out of them.
+use crate::decode::*;
+
+/// Which HOLEs to cut when the hole is controlled by a CTRL or DEVC, and so may
+/// open or close during the simulation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ControlledHoles {
+    /// Cut every HOLE, as though all controlled holes are open.
+    Open,
+    /// Only cut HOLEs without a CTRL_ID or DEVC_ID, as though all controlled
+    /// holes are closed.
+    Closed,
+}
+
+/// An axis-aligned box of solid which is part of an OBST.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SolidBox {
+    /// The index of the OBST in [`FDSFile::obsts`].
+    pub obst_index: usize,
+    /// The ID of the OBST.
+    pub id: Option<String>,
+    pub xb: XB,
+}
+
+impl FDSFile {
+    /// The solid geometry, as the parts of each OBST which remain after the
+    /// HOLEs which intersect it have been subtracted. OBSTs with
+    /// PERMIT_HOLE=.FALSE. are left whole. HOLEs with no volume (and so which
+    /// only cut anything once snapped to a grid) are ignored, as is MULT.
+    pub fn solid_boxes(&self, controlled_holes: ControlledHoles) -> Vec<SolidBox> {
+        let holes: Vec<XB> = self
+            .cut_holes(controlled_holes)
+            .map(|hole| hole.xb)
+            .filter(|xb| !xb.is_degenerate())
+            .collect();
+        let mut boxes = Vec::new();
+        for (i, obst) in self.obsts.iter().enumerate() {
+            let mut pieces = vec![obst.xb];
+            if obst.permit_hole {
+                for hole in &holes {
+                    pieces = pieces
+                        .iter()
+                        .flat_map(|piece| piece.difference(hole))
+                        .collect();
+                }
+            }
+            boxes.extend(pieces.into_iter().map(|xb| SolidBox {
+                obst_index: i,
+                id: obst.id.clone(),
+                xb,
+            }));
+        }
+        boxes
+    }
+
+    /// The HOLEs which are cut, given the state of the controlled holes.
+    pub(crate) fn cut_holes(
+        &self,
+        controlled_holes: ControlledHoles,
+    ) -> impl Iterator<Item = &Hole> + '_ {
+        self.holes
+            .iter()
+            .filter(move |hole| match controlled_holes {
+                ControlledHoles::Open => true,
+                ControlledHoles::Closed => hole.ctrl_id.is_none() && hole.devc_id.is_none(),
+            })
+    }
+}
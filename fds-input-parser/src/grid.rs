@@ -0,0 +1,413 @@
+//! The cells of a mesh, and how FDS snaps geometry onto them. FDS rounds each
+//! coordinate of an XB to the nearest cell face (in computational space, which
+//! differs from physical space when a mesh is stretched by TRNX, TRNY, or
+//! TRNZ), so the geometry that is simulated can differ from that specified.
+use crate::decode::*;
+use crate::validate::GRID_TOLERANCE;
+
+/// A block of cells within a mesh, given as the indices of the bounding cell
+/// faces. Face 0 is the lower bound of the mesh, and face I (or J or K) the
+/// upper bound. The cells occupied along x are those between faces `i1` and
+/// `i2`, so if `i1 == i2` the range is a zero-thickness sheet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CellRange {
+    pub i1: GridCoord,
+    pub i2: GridCoord,
+    pub j1: GridCoord,
+    pub j2: GridCoord,
+    pub k1: GridCoord,
+    pub k2: GridCoord,
+}
+
+impl CellRange {
+    /// The lower and upper face indices along an axis.
+    pub fn bounds(&self, axis: Plane) -> (GridCoord, GridCoord) {
+        match axis {
+            Plane::X => (self.i1, self.i2),
+            Plane::Y => (self.j1, self.j2),
+            Plane::Z => (self.k1, self.k2),
+        }
+    }
+
+    /// Whether the cell with indices `(i, j, k)` is within the range.
+    pub fn contains_cell(&self, i: GridCoord, j: GridCoord, k: GridCoord) -> bool {
+        (self.i1..self.i2).contains(&i)
+            && (self.j1..self.j2).contains(&j)
+            && (self.k1..self.k2).contains(&k)
+    }
+
+    /// The number of cells within the range. This is zero for a sheet.
+    pub fn n_cells(&self) -> u64 {
+        ((self.i2 - self.i1) * (self.j2 - self.j1) * (self.k2 - self.k1)) as u64
+    }
+}
+
+/// The grid along a single axis of a mesh.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GridAxis {
+    /// The physical coordinates of the cell faces, from the lower to the upper
+    /// bound of the mesh.
+    pub faces: Vec<f64>,
+    /// The physical coordinates of the cell centres in computational space.
+    /// A coordinate is rounded down to the face below a cell if it is below
+    /// that cell's centre, and up otherwise.
+    pub centres: Vec<f64>,
+}
+
+impl GridAxis {
+    fn new(lower: f64, upper: f64, n_cells: i64, transform: &Transform) -> Self {
+        let n = n_cells as usize;
+        let step = (upper - lower) / (n_cells as f64);
+        let faces = (0..=n)
+            .map(|i| transform.apply(lower + (i as f64) * step))
+            .collect();
+        let centres = (0..n)
+            .map(|i| transform.apply(lower + (i as f64 + 0.5) * step))
+            .collect();
+        GridAxis { faces, centres }
+    }
+
+    /// The number of cells along this axis.
+    pub fn n_cells(&self) -> usize {
+        self.centres.len()
+    }
+
+    /// The physical coordinate of a cell face, or [`None`] if there is no face
+    /// with that index.
+    pub fn face(&self, index: GridCoord) -> Option<f64> {
+        if index < 0 {
+            return None;
+        }
+        self.faces.get(index as usize).copied()
+    }
+
+    /// The index of the cell face nearest (in computational space) to a
+    /// coordinate, clamped to the bounds of the mesh.
+    pub fn snap(&self, value: f64) -> GridCoord {
+        self.centres
+            .iter()
+            .filter(|&&centre| centre <= value)
+            .count() as GridCoord
+    }
+
+    /// The index of the cell containing a coordinate, clamped to the cells of
+    /// the axis. A coordinate on a face, allowing for rounding error, belongs
+    /// to the cell above it.
+    pub fn cell(&self, value: f64) -> GridCoord {
+        let above = self
+            .faces
+            .windows(2)
+            .filter(|pair| pair[1] - (pair[1] - pair[0]) * GRID_TOLERANCE <= value)
+            .count();
+        above.min(self.n_cells().saturating_sub(1)) as GridCoord
+    }
+
+    /// The indices of the cell faces FDS snaps the bounds `lower` and `upper`
+    /// to. If they snap to the same face and `thicken` is set, as for an OBST
+    /// with THICKEN=.TRUE., the bounds are instead those of the cell
+    /// containing their midpoint.
+    pub fn snap_bounds(&self, lower: f64, upper: f64, thicken: bool) -> (GridCoord, GridCoord) {
+        let (n1, n2) = (self.snap(lower), self.snap(upper));
+        if n1 == n2 && thicken {
+            let cell = self.cell((lower + upper) / 2.0);
+            (cell, cell + 1)
+        } else {
+            (n1, n2)
+        }
+    }
+}
+
+/// The cell faces of a mesh along each axis.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Grid {
+    pub x: GridAxis,
+    pub y: GridAxis,
+    pub z: GridAxis,
+}
+
+impl Grid {
+    /// A grid with equally sized cells.
+    pub fn uniform(xb: &XB, ijk: &IJK) -> Self {
+        Grid {
+            x: GridAxis::new(xb.x1, xb.x2, ijk.i, &Transform::Identity),
+            y: GridAxis::new(xb.y1, xb.y2, ijk.j, &Transform::Identity),
+            z: GridAxis::new(xb.z1, xb.z2, ijk.k, &Transform::Identity),
+        }
+    }
+
+    pub fn axis(&self, axis: Plane) -> &GridAxis {
+        match axis {
+            Plane::X => &self.x,
+            Plane::Y => &self.y,
+            Plane::Z => &self.z,
+        }
+    }
+
+    /// The XB spanned by the grid.
+    pub fn xb(&self) -> XB {
+        self.cell_range_to_xb(&CellRange {
+            i1: 0,
+            i2: self.x.n_cells() as GridCoord,
+            j1: 0,
+            j2: self.y.n_cells() as GridCoord,
+            k1: 0,
+            k2: self.z.n_cells() as GridCoord,
+        })
+        .expect("the whole grid is a valid cell range")
+    }
+
+    /// The cells FDS will use to represent an XB, or [`None`] if the XB is not
+    /// within the grid. Parts of the XB beyond the grid are clipped.
+    pub fn snap(&self, xb: &XB) -> Option<CellRange> {
+        self.snap_thickened(xb, false)
+    }
+
+    /// As [`Grid::snap`], but if `thicken` is set the XB occupies at least one
+    /// cell along each axis (see [`GridAxis::snap_bounds`]).
+    pub fn snap_thickened(&self, xb: &XB, thicken: bool) -> Option<CellRange> {
+        let bounds = self.xb();
+        let axes = [Plane::X, Plane::Y, Plane::Z];
+        let within = axes.iter().all(|&axis| {
+            let (a1, a2) = xb.bounds(axis);
+            let (g1, g2) = bounds.bounds(axis);
+            if a1 == a2 {
+                g1 <= a1 && a1 <= g2
+            } else {
+                a2 > g1 && g2 > a1
+            }
+        });
+        if !within {
+            return None;
+        }
+        let (i1, i2) = self.x.snap_bounds(xb.x1, xb.x2, thicken);
+        let (j1, j2) = self.y.snap_bounds(xb.y1, xb.y2, thicken);
+        let (k1, k2) = self.z.snap_bounds(xb.z1, xb.z2, thicken);
+        Some(CellRange {
+            i1,
+            i2,
+            j1,
+            j2,
+            k1,
+            k2,
+        })
+    }
+
+    /// The physical extent of a range of cells, or [`None`] if any of its face
+    /// indices is outside the grid.
+    pub fn cell_range_to_xb(&self, range: &CellRange) -> Option<XB> {
+        Some(XB::new(
+            self.x.face(range.i1)?,
+            self.x.face(range.i2)?,
+            self.y.face(range.j1)?,
+            self.y.face(range.j2)?,
+            self.z.face(range.k1)?,
+            self.z.face(range.k2)?,
+        ))
+    }
+}
+
+/// A mapping from computational to physical coordinates along one axis of a
+/// mesh, as defined by TRNX, TRNY, or TRNZ.
+#[derive(Clone, Debug, PartialEq)]
+enum Transform {
+    Identity,
+    /// Straight lines between (computational, physical) points, including
+    /// the mesh bounds which map to themselves.
+    PiecewiseLinear(Vec<(f64, f64)>),
+    /// Coefficients of a polynomial, lowest order first.
+    Polynomial(Vec<f64>),
+}
+
+impl Transform {
+    /// Build the transform for an axis from (IDERIV, CC, PC) triples. As in
+    /// FDS, if any of them has IDERIV >= 0 the transform is a polynomial
+    /// fitted to those constraints, otherwise it is piecewise linear.
+    fn new(lower: f64, upper: f64, trns: &[(i64, f64, f64)]) -> Self {
+        if trns.is_empty() {
+            return Transform::Identity;
+        }
+        if trns.iter().any(|&(ideriv, _, _)| ideriv >= 0) {
+            let mut constraints = vec![(0, lower, lower), (0, upper, upper)];
+            constraints.extend(trns.iter().filter(|&&(ideriv, _, _)| ideriv >= 0));
+            Transform::Polynomial(fit_polynomial(&constraints))
+        } else {
+            let mut points = vec![(lower, lower), (upper, upper)];
+            points.extend(trns.iter().map(|&(_, cc, pc)| (cc, pc)));
+            points.sort_by(|a, b| a.0.total_cmp(&b.0));
+            Transform::PiecewiseLinear(points)
+        }
+    }
+
+    fn apply(&self, value: f64) -> f64 {
+        match self {
+            Transform::Identity => value,
+            Transform::PiecewiseLinear(points) => {
+                for pair in points.windows(2) {
+                    let (c1, p1) = pair[0];
+                    let (c2, p2) = pair[1];
+                    if value <= c2 {
+                        return p1 + (value - c1) * (p2 - p1) / (c2 - c1);
+                    }
+                }
+                value
+            }
+            Transform::Polynomial(coefficients) => coefficients
+                .iter()
+                .rev()
+                .fold(0.0, |acc, coefficient| acc * value + coefficient),
+        }
+    }
+}
+
+/// Find the polynomial whose IDERIV-th derivative at CC is PC for each of the
+/// (IDERIV, CC, PC) constraints. The polynomial has as many coefficients as
+/// there are constraints.
+fn fit_polynomial(constraints: &[(i64, f64, f64)]) -> Vec<f64> {
+    let n = constraints.len();
+    // Build the augmented matrix of the linear system.
+    let mut rows: Vec<Vec<f64>> = constraints
+        .iter()
+        .map(|&(ideriv, cc, pc)| {
+            let mut row: Vec<f64> = (0..n)
+                .map(|k| {
+                    let k = k as i64;
+                    if k < ideriv {
+                        0.0
+                    } else {
+                        let factor: f64 = ((k - ideriv + 1)..=k).map(|m| m as f64).product();
+                        factor * cc.powi((k - ideriv) as i32)
+                    }
+                })
+                .collect();
+            row.push(pc);
+            row
+        })
+        .collect();
+    // Gaussian elimination with partial pivoting.
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&a, &b| rows[a][col].abs().total_cmp(&rows[b][col].abs()))
+            .unwrap();
+        rows.swap(col, pivot);
+        let (upper, lower) = rows.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower {
+            let factor = row[col] / pivot_row[col];
+            for (value, pivot_value) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+    let mut coefficients = vec![0.0; n];
+    for row in (0..n).rev() {
+        let known: f64 = ((row + 1)..n).map(|k| rows[row][k] * coefficients[k]).sum();
+        coefficients[row] = (rows[row][n] - known) / rows[row][row];
+    }
+    coefficients
+}
+
+impl Mesh {
+    /// The cell faces of the mesh, assuming it is not stretched. Use
+    /// [`Mesh::stretched_grid`] or [`FDSFile::mesh_grid`] to take TRNX, TRNY,
+    /// and TRNZ into account.
+    pub fn grid(&self) -> Grid {
+        Grid::uniform(&self.xb, &self.ijk)
+    }
+
+    /// The cell faces of the mesh when stretched by the given TRNX, TRNY, and
+    /// TRNZ, such as those from [`FDSFile::trnxs_for_mesh`]. Any MESH_NUMBER
+    /// on them is not checked.
+    pub fn stretched_grid(&self, trnx: &[&Trnx], trny: &[&Trny], trnz: &[&Trnz]) -> Grid {
+        let trnx: Vec<(i64, f64, f64)> = trnx
+            .iter()
+            .map(|trn| (trn.ideriv, trn.cc, trn.pc))
+            .collect();
+        let trny: Vec<(i64, f64, f64)> = trny
+            .iter()
+            .map(|trn| (trn.ideriv, trn.cc, trn.pc))
+            .collect();
+        let trnz: Vec<(i64, f64, f64)> = trnz
+            .iter()
+            .map(|trn| (trn.ideriv, trn.cc, trn.pc))
+            .collect();
+        let xb = self.xb;
+        Grid {
+            x: GridAxis::new(
+                xb.x1,
+                xb.x2,
+                self.ijk.i,
+                &Transform::new(xb.x1, xb.x2, &trnx),
+            ),
+            y: GridAxis::new(
+                xb.y1,
+                xb.y2,
+                self.ijk.j,
+                &Transform::new(xb.y1, xb.y2, &trny),
+            ),
+            z: GridAxis::new(
+                xb.z1,
+                xb.z2,
+                self.ijk.k,
+                &Transform::new(xb.z1, xb.z2, &trnz),
+            ),
+        }
+    }
+
+    /// The cells FDS will use to represent an XB in the mesh when stretched by
+    /// the given TRNX, TRNY, and TRNZ (see [`Mesh::stretched_grid`]), or
+    /// [`None`] if the XB is not within the mesh.
+    pub fn snap(
+        &self,
+        xb: &XB,
+        trnx: &[&Trnx],
+        trny: &[&Trny],
+        trnz: &[&Trnz],
+    ) -> Option<CellRange> {
+        self.stretched_grid(trnx, trny, trnz).snap(xb)
+    }
+
+    /// The physical extent of a range of cells in the mesh when stretched by
+    /// the given TRNX, TRNY, and TRNZ, or [`None`] if the range is not within
+    /// the mesh.
+    pub fn cell_range_to_xb(
+        &self,
+        range: &CellRange,
+        trnx: &[&Trnx],
+        trny: &[&Trny],
+        trnz: &[&Trnz],
+    ) -> Option<XB> {
+        self.stretched_grid(trnx, trny, trnz)
+            .cell_range_to_xb(range)
+    }
+}
+
+impl FDSFile {
+    /// The cells FDS will use to represent an XB in the mesh at `mesh_index`
+    /// (0-based), or [`None`] if the XB is not within the mesh.
+    pub fn snap(&self, mesh_index: usize, xb: &XB) -> Option<CellRange> {
+        self.mesh_grid(mesh_index).snap(xb)
+    }
+
+    /// The physical extent of a range of cells in the mesh at `mesh_index`
+    /// (0-based), or [`None`] if the range is not within the mesh.
+    pub fn cell_range_to_xb(&self, mesh_index: usize, range: &CellRange) -> Option<XB> {
+        self.mesh_grid(mesh_index).cell_range_to_xb(range)
+    }
+
+    /// The cell faces of the mesh at `mesh_index` (0-based) in
+    /// [`FDSFile::meshes`], including any stretching by TRNX, TRNY, or TRNZ.
+    pub fn mesh_grid(&self, mesh_index: usize) -> Grid {
+        let mesh_number = mesh_index as i64 + 1;
+        self.meshes[mesh_index].stretched_grid(
+            &self.trnxs_for_mesh(mesh_number),
+            &self.trnys_for_mesh(mesh_number),
+            &self.trnzs_for_mesh(mesh_number),
+        )
+    }
+}
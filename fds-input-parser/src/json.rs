@@ -0,0 +1,226 @@
+//! JSON export and import of an [`FDSFile`], available with the `serde`
+//! feature.
+//!
+//! # Schema
+//!
+//! An [`FDSFile`] is a JSON object with one member per field of the struct.
+//! Every namelist type is likewise an object with one member per field, named
+//! as the FDS parameter in lower case (e.g. `SURF_ID` is `surf_id`), in the
+//! units FDS uses. Optional parameters which were not given are `null`, and
+//! parameters FDS gives a default are written with that default. Tuples (such
+//! as `surf_ids`) are arrays, and enums are strings (e.g. `"X"` for
+//! [`Plane::X`]). An [`XB`] is an object with members `x1` to `z2` (always
+//! sorted) and `reversed`, an object of three booleans recording which bounds
+//! were given in reverse order.
+//!
+//! `unknown_namelists` is an array of objects with a `name` (the namelist
+//! group, e.g. `"RADI"`) and `parameters`, mapping each parameter name to
+//! either a value, or for arrays an object mapping the Fortran index
+//! (e.g. `"1"` or `"1,2"`) to a value. Values are JSON booleans, integers,
+//! numbers, or strings for Fortran logical, integer, real, and character
+//! values.
+//!
+//! Members are only ever added to this schema, so JSON written by one version
+//! can be read by later versions. Namelists which FDS gives defaults (such as
+//! MESH and TIME) take those defaults for any members which are missing.
+//!
+//! With the `schema` feature, [`FDSFile::json_schema`] gives this schema as a
+//! JSON Schema, generated from the Rust definitions. Units are given in the
+//! description of each member.
+use crate::decode::*;
+use namelist::{Namelist, ParameterValue};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+impl FDSFile {
+    /// Export the file as JSON, following the schema described in the
+    /// [module documentation](self).
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("an FDSFile is always serializable")
+    }
+
+    /// Import a file exported by [`FDSFile::to_json`].
+    pub fn from_json(json: &str) -> serde_json::Result<FDSFile> {
+        serde_json::from_str(json)
+    }
+
+    /// A JSON Schema describing the output of [`FDSFile::to_json`]. The units
+    /// of each member are taken from the [catalog](crate::catalog).
+    #[cfg(feature = "schema")]
+    pub fn json_schema() -> String {
+        let mut schema = schemars::schema_for!(FDSFile);
+        for (name, definition) in schema.definitions.iter_mut() {
+            add_units(name, definition);
+        }
+        serde_json::to_string_pretty(&schema).expect("a schema is always serializable")
+    }
+}
+
+/// Add the units of each member of a namelist type to its description. The
+/// type maps to the namelist group of its name in upper case, and each
+/// member to the parameter of its name in upper case.
+#[cfg(feature = "schema")]
+fn add_units(name: &str, definition: &mut schemars::schema::Schema) {
+    use schemars::schema::Schema;
+    let group_name = match name {
+        "RampEntry" => "RAMP".to_string(),
+        _ => name.to_uppercase(),
+    };
+    let group = match crate::catalog::group(&group_name) {
+        Some(group) => group,
+        None => return,
+    };
+    let properties = match definition {
+        Schema::Object(object) => match object.object.as_mut() {
+            Some(validation) => &mut validation.properties,
+            None => return,
+        },
+        Schema::Bool(_) => return,
+    };
+    for (member, property) in properties.iter_mut() {
+        let units = match group
+            .parameter(&member.to_uppercase())
+            .and_then(|parameter| parameter.units)
+        {
+            Some(units) => units,
+            None => continue,
+        };
+        if let Schema::Object(property) = property {
+            let metadata = property.metadata();
+            metadata.description = Some(match metadata.description.take() {
+                Some(description) => format!("{} Units: {}.", description, units),
+                None => format!("Units: {}.", units),
+            });
+        }
+    }
+}
+
+/// A namelist which was not decoded, as it appears in JSON.
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub(crate) struct UnknownNamelist {
+    name: String,
+    parameters: BTreeMap<String, Parameter>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum Parameter {
+    Value(Value),
+    Array(BTreeMap<String, Value>),
+}
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum Value {
+    Logical(bool),
+    Integer(i64),
+    Real(f64),
+    Character(String),
+}
+
+impl Value {
+    fn from_parameter_value(pv: ParameterValue) -> Self {
+        if let Ok(x) = bool::try_from(pv.clone()) {
+            Value::Logical(x)
+        } else if let Ok(x) = i64::try_from(pv.clone()) {
+            Value::Integer(x)
+        } else if let Ok(x) = f64::try_from(pv.clone()) {
+            Value::Real(x)
+        } else {
+            Value::Character(String::try_from(pv).expect("namelist value"))
+        }
+    }
+
+    /// The value as it would be written in an FDS input file.
+    fn to_fortran(&self) -> String {
+        match self {
+            Value::Logical(true) => ".TRUE.".to_string(),
+            Value::Logical(false) => ".FALSE.".to_string(),
+            Value::Integer(x) => x.to_string(),
+            Value::Real(x) => format!("{:?}", x),
+            Value::Character(x) => format!("'{}'", x.replace('\'', "''")),
+        }
+    }
+}
+
+impl UnknownNamelist {
+    fn from_namelist(namelist: &Namelist) -> Self {
+        let parameters = namelist
+            .parameters
+            .iter()
+            .map(|(name, pv)| {
+                let parameter = match pv {
+                    ParameterValue::Atom(_) => {
+                        Parameter::Value(Value::from_parameter_value(pv.clone()))
+                    }
+                    ParameterValue::Array(vmap) => Parameter::Array(
+                        vmap.iter()
+                            .map(|(index, atom)| {
+                                let index: Vec<String> =
+                                    index.iter().map(|i| i.to_string()).collect();
+                                (
+                                    index.join(","),
+                                    Value::from_parameter_value(ParameterValue::Atom(atom.clone())),
+                                )
+                            })
+                            .collect(),
+                    ),
+                };
+                (name.to_string(), parameter)
+            })
+            .collect();
+        UnknownNamelist {
+            name: namelist.name.to_string(),
+            parameters,
+        }
+    }
+
+    /// Recreate the namelist by writing it as FDS input and parsing it again.
+    fn to_namelist(&self) -> Result<Namelist, String> {
+        let mut text = format!("&{}", self.name);
+        for (name, parameter) in &self.parameters {
+            match parameter {
+                Parameter::Value(value) => {
+                    text.push_str(&format!(" {}={}", name, value.to_fortran()));
+                }
+                Parameter::Array(values) => {
+                    for (index, value) in values {
+                        text.push_str(&format!(" {}({})={}", name, index, value.to_fortran()));
+                    }
+                }
+            }
+        }
+        text.push_str(" /\n");
+        namelist::NmlParser::new(std::io::Cursor::new(text))
+            .next()
+            .ok_or_else(|| format!("could not recreate {} namelist", self.name))
+    }
+}
+
+/// (De)serialization of [`FDSFile::unknown_namelists`].
+pub(crate) mod namelists {
+    use super::UnknownNamelist;
+    use namelist::Namelist;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        namelists: &[Namelist],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(namelists.iter().map(UnknownNamelist::from_namelist))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Namelist>, D::Error> {
+        Vec::<UnknownNamelist>::deserialize(deserializer)?
+            .iter()
+            .map(|namelist| namelist.to_namelist().map_err(D::Error::custom))
+            .collect()
+    }
+}
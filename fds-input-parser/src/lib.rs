@@ -1,7 +1,20 @@
+pub mod balance;
+pub mod catalog;
+pub mod cost;
 pub mod decode;
+pub mod detection;
+pub mod fire;
+pub mod geometry;
+pub mod grid;
+#[cfg(feature = "serde")]
+pub mod json;
+pub mod mesh;
 pub use decode::{decode_fds_file, FDSFile};
 use namelist::Namelist;
 use std::path::Path;
+pub mod spatial;
+pub mod validate;
+pub mod voxel;
 pub mod xb;
 
 pub fn parse_fds_input(input: &str) -> Vec<Namelist> {
@@ -0,0 +1,341 @@
+//! Splitting meshes into smaller meshes and merging them back together, for
+//! example to spread a simulation over more MPI processes.
+use crate::decode::*;
+use crate::validate::SubGrid;
+
+/// How close (relative to the cell size) two meshes' cell sizes and bounds
+/// must be for them to be merged.
+const MERGE_TOLERANCE: f64 = 1e-6;
+
+/// The result of rescaling the meshes of an [`FDSFile`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Rescaled {
+    /// The factor by which the number of cells along each axis (x, y, z) was
+    /// actually changed, which may differ from that requested so that the
+    /// meshes stay aligned.
+    pub factors: [f64; 3],
+    /// Objects which are thinner than a cell after rescaling, but were not
+    /// before.
+    pub sub_grid: Vec<SubGrid>,
+}
+
+/// Why meshes could not be split or merged.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MeshEditError {
+    /// There is no mesh with the given index in [`FDSFile::meshes`].
+    NoSuchMesh(usize),
+    /// The mesh, given by its index in [`FDSFile::meshes`], is stretched by
+    /// TRNX, TRNY, or TRNZ.
+    Stretched(usize),
+    /// A mesh cannot be split into fewer than one part, or more parts than it
+    /// has cells, along an axis.
+    InvalidSplit {
+        ni: GridCoord,
+        nj: GridCoord,
+        nk: GridCoord,
+    },
+    /// The meshes, given by their indices in [`FDSFile::meshes`], are the
+    /// same mesh, have different cell sizes, or do not share a whole face.
+    NotMergeable(usize, usize),
+}
+
+impl std::fmt::Display for MeshEditError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSuchMesh(mesh) => write!(f, "there is no MESH #{}", mesh + 1),
+            Self::Stretched(mesh) => write!(f, "MESH #{} is stretched", mesh + 1),
+            Self::InvalidSplit { ni, nj, nk } => write!(
+                f,
+                "a mesh cannot be split into {} × {} × {} parts",
+                ni, nj, nk
+            ),
+            Self::NotMergeable(a, b) => {
+                write!(f, "MESH #{} and MESH #{} cannot be merged", a + 1, b + 1)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MeshEditError {}
+
+fn gcd(a: GridCoord, b: GridCoord) -> GridCoord {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Split `n_cells` cells into `n` runs of consecutive cells as equal as
+/// possible, returning the index of the first cell of each run and the index
+/// after the last.
+fn split_points(n_cells: GridCoord, n: GridCoord) -> Vec<GridCoord> {
+    (0..=n).map(|m| m * n_cells / n).collect()
+}
+
+impl Mesh {
+    /// Split the mesh into `ni` × `nj` × `nk` meshes along cell boundaries, so
+    /// that every cell is unchanged. Where the cells do not divide evenly, the
+    /// meshes differ in size by at most one cell. The new meshes are ordered
+    /// with i varying fastest, then j, then k, and have IDs of the form
+    /// `ID_i_j_k` (1-based). All other parameters are copied. Returns [`None`]
+    /// if the mesh would be split into fewer than one part, or more parts than
+    /// it has cells, along an axis.
+    pub fn split(&self, ni: GridCoord, nj: GridCoord, nk: GridCoord) -> Option<Vec<Mesh>> {
+        if ni < 1 || nj < 1 || nk < 1 || ni > self.ijk.i || nj > self.ijk.j || nk > self.ijk.k {
+            return None;
+        }
+        let grid = self.grid();
+        let is = split_points(self.ijk.i, ni);
+        let js = split_points(self.ijk.j, nj);
+        let ks = split_points(self.ijk.k, nk);
+        let mut meshes = Vec::with_capacity((ni * nj * nk) as usize);
+        for k in 0..nk as usize {
+            for j in 0..nj as usize {
+                for i in 0..ni as usize {
+                    let mut mesh = self.clone();
+                    mesh.xb = XB::new(
+                        grid.x.faces[is[i] as usize],
+                        grid.x.faces[is[i + 1] as usize],
+                        grid.y.faces[js[j] as usize],
+                        grid.y.faces[js[j + 1] as usize],
+                        grid.z.faces[ks[k] as usize],
+                        grid.z.faces[ks[k + 1] as usize],
+                    );
+                    mesh.ijk = IJK {
+                        i: is[i + 1] - is[i],
+                        j: js[j + 1] - js[j],
+                        k: ks[k + 1] - ks[k],
+                    };
+                    mesh.id = self
+                        .id
+                        .as_ref()
+                        .map(|id| format!("{}_{}_{}_{}", id, i + 1, j + 1, k + 1));
+                    meshes.push(mesh);
+                }
+            }
+        }
+        Some(meshes)
+    }
+
+    /// Merge this mesh with another into a single mesh, if they have the same
+    /// cell size and share a whole face. The merged mesh takes all parameters
+    /// other than XB and IJK from this mesh.
+    pub fn merge(&self, other: &Mesh) -> Option<Mesh> {
+        let (a, b) = (self.resolution(), other.resolution());
+        let axes = [Plane::X, Plane::Y, Plane::Z];
+        let same = |x: f64, y: f64, scale: f64| (x - y).abs() <= MERGE_TOLERANCE * scale;
+        if !axes
+            .iter()
+            .all(|&axis| same(a.along(axis), b.along(axis), a.along(axis)))
+        {
+            return None;
+        }
+        let mut join = None;
+        for &axis in axes.iter() {
+            let (a1, a2) = self.xb.bounds(axis);
+            let (b1, b2) = other.xb.bounds(axis);
+            let scale = a.along(axis);
+            if same(a1, b1, scale) && same(a2, b2, scale) {
+                continue;
+            }
+            if join.is_some() {
+                return None;
+            }
+            if same(a2, b1, scale) {
+                join = Some((axis, a1, b2));
+            } else if same(b2, a1, scale) {
+                join = Some((axis, b1, a2));
+            } else {
+                return None;
+            }
+        }
+        let (axis, lower, upper) = join?;
+        let mut mesh = self.clone();
+        mesh.xb = self.xb.with_bounds(axis, lower, upper);
+        match axis {
+            Plane::X => mesh.ijk.i += other.ijk.i,
+            Plane::Y => mesh.ijk.j += other.ijk.j,
+            Plane::Z => mesh.ijk.k += other.ijk.k,
+        }
+        Some(mesh)
+    }
+}
+
+impl FDSFile {
+    /// Replace the mesh at `mesh_index` (0-based) in [`FDSFile::meshes`] with
+    /// the meshes from [`Mesh::split`]. Any OBST, VENT, or HOLE with a MESH_ID
+    /// referring to the mesh is moved to the new mesh containing its centre,
+    /// and MESH_NUMBER on TRNX, TRNY, TRNZ, and SLCF is updated for the meshes
+    /// which follow. A SLCF with the MESH_NUMBER of the split mesh is left on
+    /// the first of the new meshes. Returns an error, leaving the file
+    /// unchanged, if the split is invalid or the mesh is stretched.
+    pub fn split_mesh(
+        &mut self,
+        mesh_index: usize,
+        ni: GridCoord,
+        nj: GridCoord,
+        nk: GridCoord,
+    ) -> Result<(), MeshEditError> {
+        let mesh = self
+            .meshes
+            .get(mesh_index)
+            .ok_or(MeshEditError::NoSuchMesh(mesh_index))?;
+        if self.is_stretched(mesh_index) {
+            return Err(MeshEditError::Stretched(mesh_index));
+        }
+        let old_id = mesh.id.clone();
+        let new_meshes =
+            mesh.split(ni, nj, nk)
+                .ok_or(MeshEditError::InvalidSplit { ni, nj, nk })?;
+        let mesh_number = mesh_index as i64 + 1;
+        let n_new = new_meshes.len() as i64;
+        if let Some(old_id) = old_id {
+            let rehome = |mesh_id: &mut Option<String>, xb: XB| {
+                if mesh_id.as_ref() == Some(&old_id) {
+                    let centre = xb.center();
+                    let home = new_meshes
+                        .iter()
+                        .find(|mesh| mesh.xb.contains_point(centre))
+                        .unwrap_or(&new_meshes[0]);
+                    *mesh_id = home.id.clone();
+                }
+            };
+            for obst in self.obsts.iter_mut() {
+                rehome(&mut obst.mesh_id, obst.xb);
+            }
+            for vent in self.vents.iter_mut() {
+                if let Some(xb) = vent.xb {
+                    rehome(&mut vent.mesh_id, xb);
+                }
+            }
+            for hole in self.holes.iter_mut() {
+                rehome(&mut hole.mesh_id, hole.xb);
+            }
+        }
+        self.renumber_meshes(|n| if n > mesh_number { n + n_new - 1 } else { n });
+        self.meshes.splice(mesh_index..=mesh_index, new_meshes);
+        Ok(())
+    }
+
+    /// Merge the meshes at indices `a` and `b` (0-based) in
+    /// [`FDSFile::meshes`] using [`Mesh::merge`]. The merged mesh takes the
+    /// place of mesh `a`, and MESH_ID and SLCF MESH_NUMBER references to mesh
+    /// `b` are moved to it. Returns an error, leaving the file unchanged, if
+    /// the meshes cannot be merged or either is stretched.
+    pub fn merge_meshes(&mut self, a: usize, b: usize) -> Result<(), MeshEditError> {
+        for &index in [a, b].iter() {
+            if index >= self.meshes.len() {
+                return Err(MeshEditError::NoSuchMesh(index));
+            }
+            if self.is_stretched(index) {
+                return Err(MeshEditError::Stretched(index));
+            }
+        }
+        if a == b {
+            return Err(MeshEditError::NotMergeable(a, b));
+        }
+        let merged = self.meshes[a]
+            .merge(&self.meshes[b])
+            .ok_or(MeshEditError::NotMergeable(a, b))?;
+        if let Some(old_id) = self.meshes[b].id.clone() {
+            let rehome = |mesh_id: &mut Option<String>| {
+                if mesh_id.as_ref() == Some(&old_id) {
+                    *mesh_id = merged.id.clone();
+                }
+            };
+            self.obsts
+                .iter_mut()
+                .for_each(|obst| rehome(&mut obst.mesh_id));
+            self.vents
+                .iter_mut()
+                .for_each(|vent| rehome(&mut vent.mesh_id));
+            self.holes
+                .iter_mut()
+                .for_each(|hole| rehome(&mut hole.mesh_id));
+        }
+        let b_number = b as i64 + 1;
+        let merged_number = if a < b { a as i64 + 1 } else { a as i64 };
+        self.renumber_meshes(|n| match n {
+            n if n == b_number => merged_number,
+            n if n > b_number => n - 1,
+            n => n,
+        });
+        self.meshes[a] = merged;
+        self.meshes.remove(b);
+        Ok(())
+    }
+
+    /// Whether the mesh at `mesh_index` (0-based) is stretched by TRNX, TRNY,
+    /// or TRNZ.
+    fn is_stretched(&self, mesh_index: usize) -> bool {
+        let n = mesh_index as i64 + 1;
+        !(self.trnxs_for_mesh(n).is_empty()
+            && self.trnys_for_mesh(n).is_empty()
+            && self.trnzs_for_mesh(n).is_empty())
+    }
+
+    /// Update MESH_NUMBER on TRNX, TRNY, TRNZ, and SLCF.
+    pub(crate) fn renumber_meshes<F: Fn(i64) -> i64>(&mut self, renumber: F) {
+        for trn in self.trnxs.iter_mut() {
+            trn.mesh_number = renumber(trn.mesh_number);
+        }
+        for trn in self.trnys.iter_mut() {
+            trn.mesh_number = renumber(trn.mesh_number);
+        }
+        for trn in self.trnzs.iter_mut() {
+            trn.mesh_number = renumber(trn.mesh_number);
+        }
+        for slcf in self.slcfs.iter_mut() {
+            slcf.mesh_number = slcf.mesh_number.map(&renumber);
+        }
+    }
+
+    /// Change the number of cells in every mesh by roughly `factor` along each
+    /// axis, e.g. 2.0 to halve the cell size or 0.5 to double it. Every cell
+    /// size along an axis is scaled by the same amount, which is chosen so
+    /// that each mesh still has a whole number of cells. This keeps meshes
+    /// which were aligned (and the ratios between their cell sizes) as they
+    /// were. Along an axis, if the IJK of the meshes have greatest common
+    /// divisor G, the IJK are multiplied by round(G × factor) / G.
+    pub fn rescale_meshes(&mut self, factor: f64) -> Rescaled {
+        assert!(factor > 0.0, "the rescaling factor must be positive");
+        let before = self.validate_sub_grid();
+        let mut factors = [1.0; 3];
+        for (n, &axis) in [Plane::X, Plane::Y, Plane::Z].iter().enumerate() {
+            let cells = |mesh: &Mesh| match axis {
+                Plane::X => mesh.ijk.i,
+                Plane::Y => mesh.ijk.j,
+                Plane::Z => mesh.ijk.k,
+            };
+            let divisor = self.meshes.iter().map(cells).fold(0, gcd);
+            if divisor == 0 {
+                continue;
+            }
+            let multiple = ((divisor as f64) * factor).round().max(1.0) as GridCoord;
+            for mesh in self.meshes.iter_mut() {
+                let scaled = cells(mesh) * multiple / divisor;
+                match axis {
+                    Plane::X => mesh.ijk.i = scaled,
+                    Plane::Y => mesh.ijk.j = scaled,
+                    Plane::Z => mesh.ijk.k = scaled,
+                }
+            }
+            factors[n] = (multiple as f64) / (divisor as f64);
+        }
+        let sub_grid = self
+            .validate_sub_grid()
+            .into_iter()
+            .filter(|issue| {
+                !before.iter().any(|previous| {
+                    previous.object == issue.object
+                        && previous.mesh == issue.mesh
+                        && previous.axis == issue.axis
+                })
+            })
+            .collect();
+        Rescaled { factors, sub_grid }
+    }
+}
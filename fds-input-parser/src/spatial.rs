@@ -0,0 +1,261 @@
+//! A bounding volume hierarchy over the positioned objects of an [`FDSFile`]
+//! for fast geometric queries on large models.
+use crate::decode::*;
+use crate::xb::MightHaveXB;
+
+/// The maximum number of objects held by a leaf of the hierarchy.
+const LEAF_SIZE: usize = 4;
+
+/// The namelist groups included in a [`SpatialIndex`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ObjectKind {
+    Obst,
+    Vent,
+    Devc,
+    Mesh,
+    Hole,
+}
+
+/// An object in a [`SpatialIndex`]. `index` is the position of the object in
+/// the corresponding list of the [`FDSFile`] (e.g. [`FDSFile::obsts`]). A DEVC
+/// is stored as a zero-volume XB at its XYZ.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Entry {
+    pub kind: ObjectKind,
+    pub index: usize,
+    pub xb: XB,
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    Leaf {
+        bounds: XB,
+        entries: Vec<usize>,
+    },
+    Branch {
+        bounds: XB,
+        children: Box<(Node, Node)>,
+    },
+}
+
+impl Node {
+    fn bounds(&self) -> &XB {
+        match self {
+            Node::Leaf { bounds, .. } => bounds,
+            Node::Branch { bounds, .. } => bounds,
+        }
+    }
+}
+
+/// A spatial index over the OBSTs, VENTs, DEVCs, MESHes, and HOLEs of an
+/// [`FDSFile`]. The index is a snapshot; it is not updated if the file is
+/// changed. All tests are inclusive, so objects which only touch count as
+/// intersecting.
+#[derive(Clone, Debug)]
+pub struct SpatialIndex {
+    entries: Vec<Entry>,
+    root: Option<Node>,
+}
+
+impl SpatialIndex {
+    pub fn new(fds_file: &FDSFile) -> Self {
+        let mut entries = Vec::new();
+        for (index, obst) in fds_file.obsts.iter().enumerate() {
+            entries.push(Entry::new(ObjectKind::Obst, index, obst.xb));
+        }
+        for (index, vent) in fds_file.vents.iter().enumerate() {
+            if let Some(xb) = vent.try_xb() {
+                entries.push(Entry::new(ObjectKind::Vent, index, xb));
+            }
+        }
+        for (index, devc) in fds_file.devcs.iter().enumerate() {
+            if let Some(xyz) = devc.xyz {
+                let xb = XB::new(xyz.x, xyz.x, xyz.y, xyz.y, xyz.z, xyz.z);
+                entries.push(Entry::new(ObjectKind::Devc, index, xb));
+            }
+        }
+        for (index, mesh) in fds_file.meshes.iter().enumerate() {
+            entries.push(Entry::new(ObjectKind::Mesh, index, mesh.xb));
+        }
+        for (index, hole) in fds_file.holes.iter().enumerate() {
+            entries.push(Entry::new(ObjectKind::Hole, index, hole.xb));
+        }
+        let mut indices: Vec<usize> = (0..entries.len()).collect();
+        let root = if indices.is_empty() {
+            None
+        } else {
+            Some(build(&entries, &mut indices))
+        };
+        SpatialIndex { entries, root }
+    }
+
+    /// All of the indexed objects.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// The objects which intersect or touch an XB, grouped by kind and then
+    /// ordered by index.
+    pub fn intersecting(&self, xb: &XB) -> Vec<&Entry> {
+        let xb = xb.normalized();
+        self.search(|bounds| bounds.intersection(&xb).is_some())
+    }
+
+    /// The objects which contain a point, including on their boundary,
+    /// grouped by kind and then ordered by index.
+    pub fn containing(&self, point: XYZ) -> Vec<&Entry> {
+        self.search(|bounds| bounds.contains_point(point))
+    }
+
+    /// The object of a given kind nearest to a point, and its distance from
+    /// the point. The distance is zero if the point is within the object.
+    pub fn nearest(&self, point: XYZ, kind: ObjectKind) -> Option<(&Entry, f64)> {
+        let mut best: Option<(usize, f64)> = None;
+        if let Some(root) = &self.root {
+            self.nearest_in(root, point, kind, &mut best);
+        }
+        best.map(|(i, d2)| (&self.entries[i], d2.sqrt()))
+    }
+
+    fn nearest_in(
+        &self,
+        node: &Node,
+        point: XYZ,
+        kind: ObjectKind,
+        best: &mut Option<(usize, f64)>,
+    ) {
+        let closer = |d2: f64, best: &Option<(usize, f64)>| match best {
+            Some((_, best_d2)) => d2 < *best_d2,
+            None => true,
+        };
+        if !closer(distance_squared(node.bounds(), point), best) {
+            return;
+        }
+        match node {
+            Node::Leaf { entries, .. } => {
+                for &i in entries {
+                    let entry = &self.entries[i];
+                    if entry.kind != kind {
+                        continue;
+                    }
+                    let d2 = distance_squared(&entry.xb, point);
+                    if closer(d2, best) {
+                        *best = Some((i, d2));
+                    }
+                }
+            }
+            Node::Branch { children, .. } => {
+                // Visit the nearer child first so the farther is more likely
+                // to be pruned.
+                let (a, b) = (&children.0, &children.1);
+                if distance_squared(a.bounds(), point) <= distance_squared(b.bounds(), point) {
+                    self.nearest_in(a, point, kind, best);
+                    self.nearest_in(b, point, kind, best);
+                } else {
+                    self.nearest_in(b, point, kind, best);
+                    self.nearest_in(a, point, kind, best);
+                }
+            }
+        }
+    }
+
+    fn search<F: Fn(&XB) -> bool>(&self, test: F) -> Vec<&Entry> {
+        let mut found: Vec<&Entry> = Vec::new();
+        let mut stack: Vec<&Node> = self.root.iter().collect();
+        while let Some(node) = stack.pop() {
+            if !test(node.bounds()) {
+                continue;
+            }
+            match node {
+                Node::Leaf { entries, .. } => found.extend(
+                    entries
+                        .iter()
+                        .map(|&i| &self.entries[i])
+                        .filter(|entry| test(&entry.xb)),
+                ),
+                Node::Branch { children, .. } => {
+                    stack.push(&children.0);
+                    stack.push(&children.1);
+                }
+            }
+        }
+        // Return objects grouped by kind (in the order of `ObjectKind`), and
+        // within a kind in the order they appear in their list.
+        found.sort_by_key(|entry| (entry.kind as usize, entry.index));
+        found
+    }
+}
+
+impl Entry {
+    fn new(kind: ObjectKind, index: usize, xb: XB) -> Self {
+        Entry {
+            kind,
+            index,
+            xb: xb.normalized(),
+        }
+    }
+}
+
+/// Build a node over the entries at `indices`, splitting at the median along
+/// the axis over which the centres of the entries are most spread.
+fn build(entries: &[Entry], indices: &mut [usize]) -> Node {
+    let bounds = indices
+        .iter()
+        .map(|&i| entries[i].xb)
+        .reduce(|a, b| a.union(&b))
+        .unwrap();
+    if indices.len() <= LEAF_SIZE {
+        return Node::Leaf {
+            bounds,
+            entries: indices.to_vec(),
+        };
+    }
+    let centre = |i: usize, axis: Plane| {
+        let (lower, upper) = entries[i].xb.bounds(axis);
+        (lower + upper) / 2.0
+    };
+    let spread = |axis: Plane| {
+        let centres = indices.iter().map(|&i| centre(i, axis));
+        let min = centres.clone().fold(f64::INFINITY, f64::min);
+        let max = centres.fold(f64::NEG_INFINITY, f64::max);
+        max - min
+    };
+    let axis =
+        [Plane::Y, Plane::Z]
+            .iter()
+            .fold(Plane::X, |a, &b| if spread(b) > spread(a) { b } else { a });
+    indices.sort_by(|&a, &b| centre(a, axis).total_cmp(&centre(b, axis)));
+    let (lower, upper) = indices.split_at_mut(indices.len() / 2);
+    Node::Branch {
+        bounds,
+        children: Box::new((build(entries, lower), build(entries, upper))),
+    }
+}
+
+/// The squared distance from a point to the nearest point of an XB.
+fn distance_squared(xb: &XB, point: XYZ) -> f64 {
+    let d = |lower: f64, upper: f64, value: f64| {
+        if value < lower {
+            lower - value
+        } else if value > upper {
+            value - upper
+        } else {
+            0.0
+        }
+    };
+    let dx = d(xb.x1, xb.x2, point.x);
+    let dy = d(xb.y1, xb.y2, point.y);
+    let dz = d(xb.z1, xb.z2, point.z);
+    dx * dx + dy * dy + dz * dz
+}
+
+impl FDSFile {
+    /// Build a [`SpatialIndex`] over the objects in the file.
+    pub fn spatial_index(&self) -> SpatialIndex {
+        SpatialIndex::new(self)
+    }
+}
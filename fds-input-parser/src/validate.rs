@@ -0,0 +1,1251 @@
+//! Checks over a decoded [`FDSFile`] for errors which FDS would otherwise only
+//! report once a job has started.
+use crate::catalog;
+use crate::decode::*;
+use crate::xb::MightHaveXB;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Gas species which FDS knows about without a SPEC namelist, either from its
+/// species library or as lumped species from the simple chemistry model.
+pub const PREDEFINED_SPECIES: [&str; 53] = [
+    "ACETONE",
+    "ACETYLENE",
+    "ACROLEIN",
+    "AIR",
+    "AMMONIA",
+    "ARGON",
+    "BENZENE",
+    "BUTANE",
+    "CARBON",
+    "CARBON DIOXIDE",
+    "CARBON MONOXIDE",
+    "CHLORINE",
+    "DODECANE",
+    "ETHANE",
+    "ETHANOL",
+    "ETHYLENE",
+    "FORMALDEHYDE",
+    "HELIUM",
+    "HYDROGEN",
+    "HYDROGEN ATOM",
+    "HYDROGEN BROMIDE",
+    "HYDROGEN CHLORIDE",
+    "HYDROGEN CYANIDE",
+    "HYDROGEN FLUORIDE",
+    "HYDROGEN PEROXIDE",
+    "HYDROPEROXY RADICAL",
+    "HYDROXYL RADICAL",
+    "ISOPROPANOL",
+    "KRYPTON",
+    "METHANE",
+    "METHANOL",
+    "METHYL METHACRYLATE",
+    "N-DECANE",
+    "N-HEPTANE",
+    "N-HEXANE",
+    "N-OCTANE",
+    "NEON",
+    "NITRIC OXIDE",
+    "NITROGEN",
+    "NITROGEN ATOM",
+    "NITROGEN DIOXIDE",
+    "NITROUS OXIDE",
+    "OXYGEN",
+    "OXYGEN ATOM",
+    "PRODUCTS",
+    "PROPANE",
+    "PROPYLENE",
+    "SOOT",
+    "SULFUR DIOXIDE",
+    "SULFUR HEXAFLUORIDE",
+    "TOLUENE",
+    "WATER VAPOR",
+    "XENON",
+];
+
+/// Identifies a decoded object by its namelist group and its index into the
+/// corresponding collection of [`FDSFile`].
+///
+/// The index is not a position in the input file. [`decode_fds_file`] puts the
+/// built-in SURFs ([`BUILTIN_SURF_IDS`]) ahead of those in the file, and RAMP
+/// namelists sharing an ID are merged into one [`Ramp`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ObjectRef {
+    pub namelist: String,
+    pub index: usize,
+    pub id: Option<String>,
+}
+
+impl ObjectRef {
+    pub fn new(namelist: &str, index: usize, id: &Option<String>) -> Self {
+        ObjectRef {
+            namelist: namelist.to_string(),
+            index,
+            id: id.clone(),
+        }
+    }
+}
+
+impl fmt::Display for ObjectRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.id {
+            Some(id) => write!(f, "{} '{}' [{}]", self.namelist, id, self.index),
+            None => write!(f, "{} [{}]", self.namelist, self.index),
+        }
+    }
+}
+
+/// The kinds of object which can be referred to by ID.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum IdKind {
+    Surf,
+    Prop,
+    Ctrl,
+    Devc,
+    Ramp,
+    Matl,
+    Spec,
+    Part,
+    Mult,
+    /// Either a DEVC or a CTRL, as used by CTRL INPUT_ID.
+    Input,
+}
+
+/// A reference from one object to another by ID.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IdReference {
+    pub source: ObjectRef,
+    pub parameter: String,
+    pub kind: IdKind,
+    pub id: String,
+}
+
+impl fmt::Display for IdReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}='{}' does not exist",
+            self.source, self.parameter, self.id
+        )
+    }
+}
+
+/// The IDs which can be referred to in a model, including those FDS defines
+/// itself.
+struct KnownIds<'a> {
+    surfs: HashSet<&'a str>,
+    props: HashSet<&'a str>,
+    ctrls: HashSet<&'a str>,
+    devcs: HashSet<&'a str>,
+    ramps: HashSet<&'a str>,
+    matls: HashSet<&'a str>,
+    specs: HashSet<&'a str>,
+    parts: HashSet<&'a str>,
+    mults: HashSet<&'a str>,
+}
+
+impl<'a> KnownIds<'a> {
+    fn new(fds_file: &'a FDSFile) -> Self {
+        fn ids<'b, I: Iterator<Item = &'b Option<String>>>(ids: I) -> HashSet<&'b str> {
+            ids.filter_map(|id| id.as_deref()).collect()
+        }
+        let mut surfs = ids(fds_file.surfs.iter().map(|x| &x.id));
+        surfs.extend(BUILTIN_SURF_IDS.iter());
+        let mut specs = ids(fds_file.specs.iter().map(|x| &x.id));
+        specs.extend(PREDEFINED_SPECIES.iter());
+        specs.extend(ids(fds_file.reacs.iter().map(|x| &x.fuel)));
+        KnownIds {
+            surfs,
+            props: ids(fds_file.props.iter().map(|x| &x.id)),
+            ctrls: ids(fds_file.ctrls.iter().map(|x| &x.id)),
+            devcs: ids(fds_file.devcs.iter().map(|x| &x.id)),
+            ramps: fds_file.ramps.iter().map(|x| x.id.as_str()).collect(),
+            matls: ids(fds_file.matls.iter().map(|x| &x.id)),
+            specs,
+            parts: ids(fds_file.parts.iter().map(|x| &x.id)),
+            mults: ids(fds_file.mults.iter().map(|x| &x.id)),
+        }
+    }
+
+    fn contains(&self, kind: IdKind, id: &str) -> bool {
+        match kind {
+            IdKind::Surf => self.surfs.contains(id),
+            IdKind::Prop => self.props.contains(id),
+            IdKind::Ctrl => self.ctrls.contains(id),
+            IdKind::Devc => self.devcs.contains(id),
+            IdKind::Ramp => self.ramps.contains(id),
+            IdKind::Matl => self.matls.contains(id),
+            IdKind::Spec => self.specs.contains(id),
+            IdKind::Part => self.parts.contains(id),
+            IdKind::Mult => self.mults.contains(id),
+            IdKind::Input => id == "CONSTANT" || self.devcs.contains(id) || self.ctrls.contains(id),
+        }
+    }
+}
+
+/// Collects the references made by a single object.
+struct ReferenceCollector<'a> {
+    source: ObjectRef,
+    references: &'a mut Vec<IdReference>,
+}
+
+impl<'a> ReferenceCollector<'a> {
+    fn add(&mut self, parameter: &str, kind: IdKind, id: &Option<String>) {
+        if let Some(id) = id {
+            self.add_all(parameter, kind, std::slice::from_ref(id));
+        }
+    }
+
+    fn add_all(&mut self, parameter: &str, kind: IdKind, ids: &[String]) {
+        for id in ids {
+            if id.is_empty() {
+                continue;
+            }
+            self.references.push(IdReference {
+                source: self.source.clone(),
+                parameter: parameter.to_string(),
+                kind,
+                id: id.clone(),
+            });
+        }
+    }
+}
+
+impl FDSFile {
+    /// Every reference by ID from one decoded object to another.
+    pub fn references(&self) -> Vec<IdReference> {
+        let mut references = Vec::new();
+        macro_rules! collector {
+            ($namelist:expr, $i:expr, $id:expr) => {
+                ReferenceCollector {
+                    source: ObjectRef::new($namelist, $i, $id),
+                    references: &mut references,
+                }
+            };
+        }
+        for (i, mesh) in self.meshes.iter().enumerate() {
+            let mut c = collector!("MESH", i, &mesh.id);
+            c.add("MULT_ID", IdKind::Mult, &mesh.mult_id);
+        }
+        for (i, obst) in self.obsts.iter().enumerate() {
+            let mut c = collector!("OBST", i, &obst.id);
+            c.add("SURF_ID", IdKind::Surf, &obst.surf_id);
+            if let Some((s1, s2, s3)) = &obst.surf_ids {
+                c.add_all(
+                    "SURF_IDS",
+                    IdKind::Surf,
+                    &[s1.clone(), s2.clone(), s3.clone()],
+                );
+            }
+            if let Some((s1, s2, s3, s4, s5, s6)) = &obst.surf_id6 {
+                c.add_all(
+                    "SURF_ID6",
+                    IdKind::Surf,
+                    &[
+                        s1.clone(),
+                        s2.clone(),
+                        s3.clone(),
+                        s4.clone(),
+                        s5.clone(),
+                        s6.clone(),
+                    ],
+                );
+            }
+            c.add("CTRL_ID", IdKind::Ctrl, &obst.ctrl_id);
+            c.add("DEVC_ID", IdKind::Devc, &obst.devc_id);
+            c.add("MATL_ID", IdKind::Matl, &obst.matl_id);
+            c.add("MULT_ID", IdKind::Mult, &obst.mult_id);
+            c.add("PROP_ID", IdKind::Prop, &obst.prop_id);
+        }
+        for (i, hole) in self.holes.iter().enumerate() {
+            let mut c = collector!("HOLE", i, &hole.id);
+            c.add("CTRL_ID", IdKind::Ctrl, &hole.ctrl_id);
+            c.add("DEVC_ID", IdKind::Devc, &hole.devc_id);
+            c.add("MULT_ID", IdKind::Mult, &hole.mult_id);
+        }
+        for (i, vent) in self.vents.iter().enumerate() {
+            let mut c = collector!("VENT", i, &vent.id);
+            c.add("SURF_ID", IdKind::Surf, &vent.surf_id);
+            c.add("CTRL_ID", IdKind::Ctrl, &vent.ctrl_id);
+            c.add("DEVC_ID", IdKind::Devc, &vent.devc_id);
+            c.add("MULT_ID", IdKind::Mult, &vent.mult_id);
+        }
+        for (i, devc) in self.devcs.iter().enumerate() {
+            let mut c = collector!("DEVC", i, &devc.id);
+            c.add("PROP_ID", IdKind::Prop, &devc.prop_id);
+            c.add("CTRL_ID", IdKind::Ctrl, &devc.ctrl_id);
+            c.add("DEVC_ID", IdKind::Devc, &devc.devc_id);
+            c.add("MATL_ID", IdKind::Matl, &devc.matl_id);
+            c.add("PART_ID", IdKind::Part, &devc.part_id);
+            c.add("SPEC_ID", IdKind::Spec, &devc.spec_id);
+        }
+        for (i, ctrl) in self.ctrls.iter().enumerate() {
+            let mut c = collector!("CTRL", i, &ctrl.id);
+            c.add_all("INPUT_ID", IdKind::Input, &ctrl.input_id);
+            c.add("RAMP_ID", IdKind::Ramp, &ctrl.ramp_id);
+        }
+        for (i, ramp) in self.ramps.iter().enumerate() {
+            let mut c = collector!("RAMP", i, &Some(ramp.id.clone()));
+            for entry in &ramp.entries {
+                c.add("CTRL_ID", IdKind::Ctrl, &entry.ctrl_id);
+                c.add("DEVC_ID", IdKind::Devc, &entry.devc_id);
+            }
+        }
+        for (i, surf) in self.surfs.iter().enumerate() {
+            let mut c = collector!("SURF", i, &surf.id);
+            c.add_all("MATL_ID", IdKind::Matl, &surf.matl_id);
+            c.add("PART_ID", IdKind::Part, &surf.part_id);
+            c.add("RAMP_Q", IdKind::Ramp, &surf.ramp_q);
+            c.add("RAMP_T", IdKind::Ramp, &surf.ramp_t);
+            c.add("RAMP_V", IdKind::Ramp, &surf.ramp_v);
+            c.add_all("SPEC_ID", IdKind::Spec, &surf.spec_id);
+        }
+        for (i, matl) in self.matls.iter().enumerate() {
+            let mut c = collector!("MATL", i, &matl.id);
+            c.add_all("MATL_ID", IdKind::Matl, &matl.matl_id);
+            c.add_all("SPEC_ID", IdKind::Spec, &matl.spec_id);
+            c.add("CONDUCTIVITY_RAMP", IdKind::Ramp, &matl.conductivity_ramp);
+            c.add("SPECIFIC_HEAT_RAMP", IdKind::Ramp, &matl.specific_heat_ramp);
+        }
+        for (i, spec) in self.specs.iter().enumerate() {
+            let mut c = collector!("SPEC", i, &spec.id);
+            c.add_all("SPEC_ID", IdKind::Spec, &spec.spec_id);
+        }
+        for (i, reac) in self.reacs.iter().enumerate() {
+            let mut c = collector!("REAC", i, &None);
+            c.add_all("SPEC_ID_NU", IdKind::Spec, &reac.spec_id_nu);
+        }
+        for (i, prop) in self.props.iter().enumerate() {
+            let mut c = collector!("PROP", i, &prop.id);
+            c.add("PART_ID", IdKind::Part, &prop.part_id);
+        }
+        for (i, part) in self.parts.iter().enumerate() {
+            let mut c = collector!("PART", i, &part.id);
+            c.add("SURF_ID", IdKind::Surf, &part.surf_id);
+            c.add("PROP_ID", IdKind::Prop, &part.prop_id);
+            c.add("SPEC_ID", IdKind::Spec, &part.spec_id);
+            c.add("CTRL_ID", IdKind::Ctrl, &part.ctrl_id);
+            c.add("DEVC_ID", IdKind::Devc, &part.devc_id);
+        }
+        references
+    }
+
+    /// Find every reference by ID which does not resolve to a decoded object
+    /// or to one of the objects FDS defines itself (such as the INERT and OPEN
+    /// surfaces).
+    pub fn validate_references(&self) -> Vec<IdReference> {
+        let known = KnownIds::new(self);
+        self.references()
+            .into_iter()
+            .filter(|reference| !known.contains(reference.kind, &reference.id))
+            .collect()
+    }
+}
+
+/// An object which reuses an ID already taken by an earlier object in the same
+/// namespace.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DuplicateId {
+    pub id: String,
+    pub first: ObjectRef,
+    pub duplicate: ObjectRef,
+    /// The positions of the first and the duplicate definition in the input,
+    /// each counted from 1 among the namelists of its own group (so the 2nd
+    /// SURF in the file is 2, whatever else comes between).
+    pub positions: (usize, usize),
+}
+
+impl fmt::Display for DuplicateId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} #{}: ID '{}' is already used by {} #{}",
+            self.duplicate.namelist,
+            self.positions.1,
+            self.id,
+            self.first.namelist,
+            self.positions.0
+        )
+    }
+}
+
+/// Find the duplicates within a single namespace of IDs, given each object
+/// with its position in the input. Objects without an ID are ignored.
+fn find_duplicates(objects: Vec<(ObjectRef, usize)>, duplicates: &mut Vec<DuplicateId>) {
+    let mut seen: HashMap<String, (ObjectRef, usize)> = HashMap::new();
+    for (object, position) in objects {
+        let id = match &object.id {
+            Some(id) => id.clone(),
+            None => continue,
+        };
+        match seen.get(&id) {
+            Some((first, first_position)) => duplicates.push(DuplicateId {
+                id,
+                first: first.clone(),
+                duplicate: object,
+                positions: (*first_position, position),
+            }),
+            None => {
+                seen.insert(id, (object, position));
+            }
+        }
+    }
+}
+
+impl FDSFile {
+    /// Find IDs which are defined more than once within a namespace. Each
+    /// namelist group has its own namespace, except for DEVC and CTRL which
+    /// share one as both can be used as a CTRL INPUT_ID. OBST and HOLE IDs are
+    /// not required to be unique and are not checked. RAMP namelists sharing
+    /// an ID are parts of the same ramp and are not duplicates. The built-in
+    /// SURFs are not definitions in the input and are not checked.
+    pub fn validate_unique_ids(&self) -> Vec<DuplicateId> {
+        fn refs<'a, I: Iterator<Item = &'a Option<String>>>(
+            namelist: &'static str,
+            ids: I,
+        ) -> Vec<(ObjectRef, usize)> {
+            ids.enumerate()
+                .map(|(i, id)| (ObjectRef::new(namelist, i, id), i + 1))
+                .collect()
+        }
+        let mut duplicates = Vec::new();
+        find_duplicates(
+            refs("MESH", self.meshes.iter().map(|x| &x.id)),
+            &mut duplicates,
+        );
+        let builtin_surfs = self.builtin_surfs;
+        find_duplicates(
+            refs("SURF", self.surfs.iter().map(|x| &x.id))
+                .into_iter()
+                .skip(builtin_surfs)
+                .map(|(object, i)| (object, i - builtin_surfs))
+                .collect(),
+            &mut duplicates,
+        );
+        find_duplicates(
+            refs("MATL", self.matls.iter().map(|x| &x.id)),
+            &mut duplicates,
+        );
+        find_duplicates(
+            refs("SPEC", self.specs.iter().map(|x| &x.id)),
+            &mut duplicates,
+        );
+        find_duplicates(
+            refs("PART", self.parts.iter().map(|x| &x.id)),
+            &mut duplicates,
+        );
+        find_duplicates(
+            refs("PROP", self.props.iter().map(|x| &x.id)),
+            &mut duplicates,
+        );
+        find_duplicates(
+            refs("MULT", self.mults.iter().map(|x| &x.id)),
+            &mut duplicates,
+        );
+        find_duplicates(
+            refs("VENT", self.vents.iter().map(|x| &x.id)),
+            &mut duplicates,
+        );
+        let mut devcs_and_ctrls = refs("DEVC", self.devcs.iter().map(|x| &x.id));
+        devcs_and_ctrls.extend(refs("CTRL", self.ctrls.iter().map(|x| &x.id)));
+        find_duplicates(devcs_and_ctrls, &mut duplicates);
+        duplicates
+    }
+}
+
+/// The aspect ratio above which [`FDSFile::validate_meshes`] reports a mesh's
+/// cells as being too elongated.
+pub const DEFAULT_MAX_ASPECT_RATIO: f64 = 2.0;
+
+/// How far (as a fraction of a cell) a coordinate may be from a grid line and
+/// still be considered to lie on it.
+pub(crate) const GRID_TOLERANCE: f64 = 1e-4;
+
+const AXES: [Plane; 3] = [Plane::X, Plane::Y, Plane::Z];
+
+/// A problem with the arrangement of meshes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MeshIssue {
+    /// Two meshes overlap, which duplicates the computation in the overlapping
+    /// region.
+    Overlap {
+        a: ObjectRef,
+        b: ObjectRef,
+        volume: f64,
+    },
+    /// Two meshes abut, but along an axis parallel to their interface one
+    /// mesh's cells are not a whole number of the other's.
+    CellSizeRatio {
+        a: ObjectRef,
+        b: ObjectRef,
+        axis: Plane,
+        ratio: f64,
+    },
+    /// Two meshes abut with integer cell size ratios, but the cell faces of
+    /// the coarser mesh do not line up with those of the finer mesh.
+    Misaligned {
+        a: ObjectRef,
+        b: ObjectRef,
+        axis: Plane,
+        position: f64,
+    },
+    /// The ratio of a mesh's longest to shortest cell side is too high.
+    AspectRatio { mesh: ObjectRef, aspect_ratio: f64 },
+}
+
+impl fmt::Display for MeshIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overlap { a, b, volume } => {
+                write!(f, "{} overlaps {} by {} m³", a, b, volume)
+            }
+            Self::CellSizeRatio { a, b, axis, ratio } => write!(
+                f,
+                "{} and {} abut but their {:?} cell size ratio is {}, not an integer",
+                a, b, axis, ratio
+            ),
+            Self::Misaligned {
+                a,
+                b,
+                axis,
+                position,
+            } => write!(
+                f,
+                "{} and {} abut but their cell faces do not align along {:?} at {}",
+                a, b, axis, position
+            ),
+            Self::AspectRatio { mesh, aspect_ratio } => {
+                write!(f, "{} has a cell aspect ratio of {}", mesh, aspect_ratio)
+            }
+        }
+    }
+}
+
+/// Test if a value is within [`GRID_TOLERANCE`] of a whole number.
+fn is_whole(value: f64) -> bool {
+    (value - value.round()).abs() < GRID_TOLERANCE
+}
+
+/// Test if a coordinate lies on a grid line of a uniform grid.
+fn on_grid(position: f64, origin: f64, cell_size: f64) -> bool {
+    is_whole((position - origin) / cell_size)
+}
+
+/// If two XBs share a face (touch without overlapping, with an interface of
+/// non-zero area), return the axis normal to that face.
+pub(crate) fn abutting_axis(a: &XB, b: &XB, tolerance: f64) -> Option<Plane> {
+    let mut touching = None;
+    for &axis in AXES.iter() {
+        let (a1, a2) = a.bounds(axis);
+        let (b1, b2) = b.bounds(axis);
+        if (a2 - b1).abs() < tolerance || (b2 - a1).abs() < tolerance {
+            if touching.is_some() {
+                return None;
+            }
+            touching = Some(axis);
+        } else if a2.min(b2) - a1.max(b1) <= 0.0 {
+            return None;
+        }
+    }
+    touching
+}
+
+/// Check the cells of two meshes which share an interface normal to
+/// `normal`, returning the first problem found.
+fn check_interface(
+    a: (&Mesh, ObjectRef),
+    b: (&Mesh, ObjectRef),
+    normal: Plane,
+) -> Option<MeshIssue> {
+    let (a_mesh, a_ref) = a;
+    let (b_mesh, b_ref) = b;
+    let a_res = a_mesh.resolution();
+    let b_res = b_mesh.resolution();
+    for &axis in AXES.iter().filter(|&&axis| axis != normal) {
+        let da = a_res.along(axis);
+        let db = b_res.along(axis);
+        let ratio = da.max(db) / da.min(db);
+        if !is_whole(ratio) {
+            return Some(MeshIssue::CellSizeRatio {
+                a: a_ref,
+                b: b_ref,
+                axis,
+                ratio,
+            });
+        }
+        let (a1, a2) = a_mesh.xb.bounds(axis);
+        let (b1, b2) = b_mesh.xb.bounds(axis);
+        let lo = a1.max(b1);
+        let hi = a2.min(b2);
+        // Every face of the coarser mesh within the interface must also be a
+        // face of the finer mesh.
+        let (coarse_origin, coarse_size) = if da >= db { (a1, da) } else { (b1, db) };
+        let first = ((lo - coarse_origin) / coarse_size - GRID_TOLERANCE).ceil() as i64;
+        let mut positions = vec![lo, hi];
+        let mut n = first;
+        loop {
+            let position = coarse_origin + (n as f64) * coarse_size;
+            if position > hi + coarse_size * GRID_TOLERANCE {
+                break;
+            }
+            positions.push(position);
+            n += 1;
+        }
+        for position in positions {
+            if !on_grid(position, a1, da) || !on_grid(position, b1, db) {
+                return Some(MeshIssue::Misaligned {
+                    a: a_ref,
+                    b: b_ref,
+                    axis,
+                    position,
+                });
+            }
+        }
+    }
+    None
+}
+
+impl FDSFile {
+    /// Check the meshes for overlaps, for interfaces where the cells of
+    /// neighbouring meshes do not line up, and for cells with an aspect ratio
+    /// greater than `max_aspect_ratio`. Meshes are assumed to be uniform, any
+    /// TRNX, TRNY or TRNZ stretching is not taken into account.
+    pub fn validate_meshes(&self, max_aspect_ratio: f64) -> Vec<MeshIssue> {
+        let mut issues = Vec::new();
+        for (i, mesh) in self.meshes.iter().enumerate() {
+            let res = mesh.resolution();
+            let min_side = res.x.min(res.y).min(res.z);
+            let aspect_ratio = res.max_side() / min_side;
+            if aspect_ratio > max_aspect_ratio {
+                issues.push(MeshIssue::AspectRatio {
+                    mesh: ObjectRef::new("MESH", i, &mesh.id),
+                    aspect_ratio,
+                });
+            }
+        }
+        for (i, a) in self.meshes.iter().enumerate() {
+            for (j, b) in self.meshes.iter().enumerate().skip(i + 1) {
+                let a_ref = ObjectRef::new("MESH", i, &a.id);
+                let b_ref = ObjectRef::new("MESH", j, &b.id);
+                if a.xb.intersect(&b.xb) {
+                    let volume: f64 = AXES
+                        .iter()
+                        .map(|&axis| {
+                            let (a1, a2) = a.xb.bounds(axis);
+                            let (b1, b2) = b.xb.bounds(axis);
+                            a2.min(b2) - a1.max(b1)
+                        })
+                        .product();
+                    issues.push(MeshIssue::Overlap {
+                        a: a_ref,
+                        b: b_ref,
+                        volume,
+                    });
+                    continue;
+                }
+                let tolerance =
+                    GRID_TOLERANCE * a.resolution().max_side().min(b.resolution().max_side());
+                if let Some(normal) = abutting_axis(&a.xb, &b.xb, tolerance) {
+                    if let Some(issue) = check_interface((a, a_ref), (b, b_ref), normal) {
+                        issues.push(issue);
+                    }
+                }
+            }
+        }
+        issues
+    }
+}
+
+/// How much of an object lies outside every mesh.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Coverage {
+    /// The object is entirely outside the meshes, and will be ignored by FDS.
+    Outside,
+    /// Only part of the object is within the meshes. The boxes are the parts
+    /// which will be clipped.
+    Partial { clipped: Vec<XB> },
+}
+
+/// An object which is not entirely within the meshes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Uncovered {
+    pub object: ObjectRef,
+    pub coverage: Coverage,
+}
+
+impl fmt::Display for Uncovered {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.coverage {
+            Coverage::Outside => write!(f, "{} is outside all meshes", self.object),
+            Coverage::Partial { clipped } => write!(
+                f,
+                "{} is partially outside the meshes ({} region(s) clipped)",
+                self.object,
+                clipped.len()
+            ),
+        }
+    }
+}
+
+impl FDSFile {
+    /// Determine how much of an XB lies outside the union of the meshes.
+    /// Returns [`None`] if it is entirely within the meshes.
+    pub fn mesh_coverage(&self, xb: &XB) -> Option<Coverage> {
+        let mut clipped = vec![*xb];
+        for mesh in &self.meshes {
+            clipped = clipped
+                .iter()
+                .flat_map(|piece| piece.difference(&mesh.xb))
+                .collect();
+            if clipped.is_empty() {
+                return None;
+            }
+        }
+        if clipped.len() == 1 && clipped[0] == *xb {
+            Some(Coverage::Outside)
+        } else {
+            Some(Coverage::Partial { clipped })
+        }
+    }
+
+    /// Find the OBSTs, VENTs, DEVCs, and SLCFs which lie wholly or partially
+    /// outside the meshes.
+    pub fn validate_mesh_coverage(&self) -> Vec<Uncovered> {
+        fn check<'a, I: Iterator<Item = (Option<XB>, &'a Option<String>)>>(
+            fds_file: &FDSFile,
+            namelist: &'static str,
+            objects: I,
+            uncovered: &mut Vec<Uncovered>,
+        ) {
+            for (i, (xb, id)) in objects.enumerate() {
+                if let Some(xb) = xb {
+                    if let Some(coverage) = fds_file.mesh_coverage(&xb) {
+                        uncovered.push(Uncovered {
+                            object: ObjectRef::new(namelist, i, id),
+                            coverage,
+                        });
+                    }
+                }
+            }
+        }
+        let mut uncovered = Vec::new();
+        check(
+            self,
+            "OBST",
+            self.obsts.iter().map(|x| (x.try_xb(), &x.id)),
+            &mut uncovered,
+        );
+        check(
+            self,
+            "VENT",
+            self.vents.iter().map(|x| (x.try_xb(), &x.id)),
+            &mut uncovered,
+        );
+        for (i, devc) in self.devcs.iter().enumerate() {
+            if let Some(xyz) = &devc.xyz {
+                if !self.meshes.iter().any(|mesh| mesh.xb.contains_point(*xyz)) {
+                    uncovered.push(Uncovered {
+                        object: ObjectRef::new("DEVC", i, &devc.id),
+                        coverage: Coverage::Outside,
+                    });
+                }
+            }
+        }
+        check(
+            self,
+            "SLCF",
+            self.slcfs.iter().map(|x| (x.try_xb(), &x.id)),
+            &mut uncovered,
+        );
+        for (i, slcf) in self.slcfs.iter().enumerate() {
+            let planes = [
+                (Plane::X, slcf.pbx),
+                (Plane::Y, slcf.pby),
+                (Plane::Z, slcf.pbz),
+            ];
+            for &(axis, position) in planes.iter() {
+                if let Some(position) = position {
+                    let in_a_mesh = self.meshes.iter().any(|mesh| {
+                        let (lower, upper) = mesh.xb.bounds(axis);
+                        lower <= position && position <= upper
+                    });
+                    if !in_a_mesh {
+                        uncovered.push(Uncovered {
+                            object: ObjectRef::new("SLCF", i, &slcf.id),
+                            coverage: Coverage::Outside,
+                        });
+                    }
+                }
+            }
+        }
+        uncovered
+    }
+}
+
+/// How far apart (in metres) two coordinates may be and still be considered
+/// the same position, such as a vent lying on the face of an obstruction.
+const POSITION_TOLERANCE: f64 = 1e-6;
+
+/// A problem with where a VENT has been placed.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum VentIssue {
+    /// The vent does not have zero thickness along exactly one axis.
+    NonPlanar { vent: ObjectRef },
+    /// Some of the vent is neither on the face of an OBST nor on an exterior
+    /// mesh boundary.
+    Floating {
+        vent: ObjectRef,
+        unsupported_area: f64,
+    },
+    /// The vent crosses the boundary between meshes.
+    Straddling {
+        vent: ObjectRef,
+        meshes: Vec<ObjectRef>,
+    },
+    /// A supply or extract vent which has solid (or the outside of the
+    /// meshes) on both sides, so no gas can flow through it.
+    NotExposed { vent: ObjectRef },
+}
+
+impl fmt::Display for VentIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonPlanar { vent } => write!(f, "{} is not planar", vent),
+            Self::Floating {
+                vent,
+                unsupported_area,
+            } => write!(
+                f,
+                "{} has {} m² which is not on a solid surface",
+                vent, unsupported_area
+            ),
+            Self::Straddling { vent, meshes } => {
+                write!(f, "{} straddles {} meshes", vent, meshes.len())
+            }
+            Self::NotExposed { vent } => {
+                write!(f, "{} is not exposed to the gas phase", vent)
+            }
+        }
+    }
+}
+
+/// The axis along which an XB has no thickness, if there is exactly one.
+fn flat_axis(xb: &XB) -> Option<Plane> {
+    let mut flat = AXES.iter().filter(|&&axis| {
+        let (lower, upper) = xb.bounds(axis);
+        lower == upper
+    });
+    match (flat.next(), flat.next()) {
+        (Some(&axis), None) => Some(axis),
+        _ => None,
+    }
+}
+
+/// The area of overlap between two XBs which are flat along `normal`.
+fn overlap_area(a: &XB, b: &XB, normal: Plane) -> f64 {
+    AXES.iter()
+        .filter(|&&axis| axis != normal)
+        .map(|&axis| {
+            let (a1, a2) = a.bounds(axis);
+            let (b1, b2) = b.bounds(axis);
+            (a2.min(b2) - a1.max(b1)).max(0.0)
+        })
+        .product()
+}
+
+/// Subtract each of `covers` from `face`, returning what remains.
+fn uncovered_parts<I: Iterator<Item = XB>>(face: &XB, covers: I) -> Vec<XB> {
+    let mut remaining = vec![*face];
+    for cover in covers {
+        remaining = remaining
+            .iter()
+            .flat_map(|piece| piece.difference(&cover))
+            .collect();
+        if remaining.is_empty() {
+            break;
+        }
+    }
+    remaining
+}
+
+impl FDSFile {
+    /// The part of a face (an XB with no thickness along `normal`) which has gas
+    /// on the side given by `positive`, i.e. is within a mesh and not within
+    /// an OBST on that side.
+    fn gas_side(&self, face: &XB, normal: Plane, positive: bool) -> Vec<XB> {
+        let (position, _) = face.bounds(normal);
+        let covers_side = |xb: &XB| {
+            let (lower, upper) = xb.bounds(normal);
+            if positive {
+                lower <= position + POSITION_TOLERANCE && upper > position + POSITION_TOLERANCE
+            } else {
+                lower < position - POSITION_TOLERANCE && upper >= position - POSITION_TOLERANCE
+            }
+        };
+        let outside_meshes = uncovered_parts(
+            face,
+            self.meshes
+                .iter()
+                .map(|mesh| mesh.xb)
+                .filter(|xb| covers_side(xb)),
+        );
+        let in_meshes = uncovered_parts(face, outside_meshes.into_iter());
+        in_meshes
+            .iter()
+            .flat_map(|piece| {
+                uncovered_parts(
+                    piece,
+                    self.obsts
+                        .iter()
+                        .map(|obst| obst.xb)
+                        .filter(|xb| covers_side(xb)),
+                )
+            })
+            .collect()
+    }
+
+    /// Check that each VENT is planar, sits on a solid surface (an OBST face
+    /// or an exterior mesh boundary), does not cross between meshes, and,
+    /// for supply and extract vents, is exposed to the gas phase.
+    pub fn validate_vents(&self) -> Vec<VentIssue> {
+        let mut issues = Vec::new();
+        for (i, vent) in self.vents.iter().enumerate() {
+            let vent_ref = ObjectRef::new("VENT", i, &vent.id);
+            let xb = match vent.xb {
+                Some(xb) => xb,
+                None => continue,
+            };
+            let normal = match flat_axis(&xb) {
+                Some(normal) => normal,
+                None => {
+                    issues.push(VentIssue::NonPlanar { vent: vent_ref });
+                    continue;
+                }
+            };
+            let (position, _) = xb.bounds(normal);
+
+            // A vent is supported wherever there is solid on at least one side.
+            let below = self.gas_side(&xb, normal, false);
+            let above = self.gas_side(&xb, normal, true);
+            let unsupported: f64 = below
+                .iter()
+                .flat_map(|b| above.iter().map(move |a| overlap_area(a, b, normal)))
+                .sum();
+            if unsupported > 0.0 {
+                issues.push(VentIssue::Floating {
+                    vent: vent_ref.clone(),
+                    unsupported_area: unsupported,
+                });
+            }
+
+            let meshes: Vec<ObjectRef> = self
+                .meshes
+                .iter()
+                .enumerate()
+                .filter(|(_, mesh)| {
+                    let (lower, upper) = mesh.xb.bounds(normal);
+                    lower <= position
+                        && position <= upper
+                        && AXES.iter().filter(|&&axis| axis != normal).all(|&axis| {
+                            let (m1, m2) = mesh.xb.bounds(axis);
+                            let (v1, v2) = xb.bounds(axis);
+                            m2.min(v2) > m1.max(v1)
+                        })
+                })
+                .map(|(j, mesh)| ObjectRef::new("MESH", j, &mesh.id))
+                .collect();
+            let within_one_mesh = self.meshes.iter().any(|mesh| {
+                AXES.iter().filter(|&&axis| axis != normal).all(|&axis| {
+                    let (m1, m2) = mesh.xb.bounds(axis);
+                    let (v1, v2) = xb.bounds(axis);
+                    m1 <= v1 && v2 <= m2
+                })
+            });
+            if meshes.len() > 1 && !within_one_mesh {
+                issues.push(VentIssue::Straddling {
+                    vent: vent_ref.clone(),
+                    meshes,
+                });
+            }
+
+            if (vent.is_supply(self) || vent.is_extract(self))
+                && below.is_empty()
+                && above.is_empty()
+            {
+                issues.push(VentIssue::NotExposed { vent: vent_ref });
+            }
+        }
+        issues
+    }
+}
+
+/// How FDS represents an object along an axis where it is thinner than a cell.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SubGridSnap {
+    /// Both bounds round to the same cell face. An OBST becomes a
+    /// zero-thickness sheet, a VENT disappears.
+    ZeroThickness,
+    /// The bounds round to neighbouring cell faces, so the object is one cell
+    /// thick. An OBST with THICKEN=.TRUE. whose bounds round to the same face
+    /// instead fills the cell containing its midpoint.
+    OneCell,
+}
+
+/// An object which is thinner than the cells of a mesh it sits in.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SubGrid {
+    pub object: ObjectRef,
+    pub mesh: ObjectRef,
+    pub axis: Plane,
+    pub extent: f64,
+    pub cell_size: f64,
+    pub snap: SubGridSnap,
+    /// The bounds along `axis` which FDS will use for the object, as in
+    /// [`FDSFile::voxelize`].
+    pub snapped: (f64, f64),
+}
+
+impl fmt::Display for SubGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let snap = match self.snap {
+            SubGridSnap::ZeroThickness => "zero thickness",
+            SubGridSnap::OneCell => "one cell",
+        };
+        write!(
+            f,
+            "{} is {} m along {:?} in {} with {} m cells, and will be snapped to {} ({} to {})",
+            self.object,
+            self.extent,
+            self.axis,
+            self.mesh,
+            self.cell_size,
+            snap,
+            self.snapped.0,
+            self.snapped.1
+        )
+    }
+}
+
+/// Test if an XB occupies any part of a mesh. An axis along which the XB has no
+/// thickness counts if it lies within or on the bounds of the mesh.
+fn occupies(xb: &XB, mesh: &Mesh) -> bool {
+    AXES.iter().all(|&axis| {
+        let (a1, a2) = xb.bounds(axis);
+        let (m1, m2) = mesh.xb.bounds(axis);
+        if a1 == a2 {
+            m1 <= a1 && a1 <= m2
+        } else {
+            a2 > m1 && m2 > a1
+        }
+    })
+}
+
+impl FDSFile {
+    /// Find OBSTs and VENTs which, along some axis, are thinner than the cells
+    /// of a mesh they sit in. Axes along which an object has been given no
+    /// thickness (such as the normal of a vent) are not reported. The cell size
+    /// is that of the cell containing the middle of the object, so stretched
+    /// meshes are taken into account.
+    pub fn validate_sub_grid(&self) -> Vec<SubGrid> {
+        let mut sub_grid = Vec::new();
+        let mut check = |object: ObjectRef, xb: &XB, thicken: bool| {
+            for (j, mesh) in self.meshes.iter().enumerate() {
+                if !occupies(xb, mesh) {
+                    continue;
+                }
+                let grid = self.mesh_grid(j);
+                for &axis in AXES.iter() {
+                    let (a1, a2) = xb.bounds(axis);
+                    let extent = a2 - a1;
+                    let grid_axis = grid.axis(axis);
+                    let cell = grid_axis.cell((a1 + a2) / 2.0);
+                    let cell_size = match (grid_axis.face(cell), grid_axis.face(cell + 1)) {
+                        (Some(lower), Some(upper)) => upper - lower,
+                        _ => continue,
+                    };
+                    // Allow for rounding error in an extent of exactly one
+                    // cell.
+                    if extent <= 0.0 || extent >= cell_size * (1.0 - GRID_TOLERANCE) {
+                        continue;
+                    }
+                    let (n1, n2) = grid_axis.snap_bounds(a1, a2, thicken);
+                    let snap = if n1 == n2 {
+                        SubGridSnap::ZeroThickness
+                    } else {
+                        SubGridSnap::OneCell
+                    };
+                    let snapped = (
+                        grid_axis.face(n1).unwrap_or(a1),
+                        grid_axis.face(n2).unwrap_or(a2),
+                    );
+                    sub_grid.push(SubGrid {
+                        object: object.clone(),
+                        mesh: ObjectRef::new("MESH", j, &mesh.id),
+                        axis,
+                        extent,
+                        cell_size,
+                        snap,
+                        snapped,
+                    });
+                }
+            }
+        };
+        for (i, obst) in self.obsts.iter().enumerate() {
+            check(ObjectRef::new("OBST", i, &obst.id), &obst.xb, obst.thicken);
+        }
+        for (i, vent) in self.vents.iter().enumerate() {
+            if let Some(xb) = &vent.xb {
+                check(ObjectRef::new("VENT", i, &vent.id), xb, false);
+            }
+        }
+        sub_grid
+    }
+}
+
+/// A namelist group or parameter name which FDS does not accept, with the
+/// closest valid name if there is one which is a likely typo.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum UnknownName {
+    Parameter {
+        parameter: UnknownParameter,
+        suggestion: Option<String>,
+    },
+    /// A namelist in [`FDSFile::unknown_namelists`] whose group is not an FDS
+    /// namelist group.
+    Namelist {
+        index: usize,
+        name: String,
+        suggestion: Option<String>,
+    },
+}
+
+impl fmt::Display for UnknownName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let suggestion = match self {
+            Self::Parameter {
+                parameter,
+                suggestion,
+            } => {
+                match &parameter.id {
+                    Some(id) => write!(f, "{} '{}': ", parameter.namelist, id)?,
+                    None => write!(f, "{}: ", parameter.namelist)?,
+                }
+                write!(
+                    f,
+                    "{} is not a parameter of {}",
+                    parameter.parameter, parameter.namelist
+                )?;
+                suggestion
+            }
+            Self::Namelist {
+                name, suggestion, ..
+            } => {
+                write!(f, "&{} is not an FDS namelist group", name)?;
+                suggestion
+            }
+        };
+        match suggestion {
+            Some(suggestion) => write!(f, ", did you mean {}?", suggestion),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The number of single-character insertions, deletions, substitutions, or
+/// transpositions of adjacent characters needed to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+/// The candidate closest to `name`, if it is close enough to be a likely typo:
+/// within one edit for every three characters, and at least one.
+fn closest<'a, I: Iterator<Item = &'a str>>(name: &str, candidates: I) -> Option<String> {
+    let limit = (name.chars().count() / 3).max(1);
+    candidates
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= limit)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate.to_string())
+}
+
+impl FDSFile {
+    /// Find the parameters which are not valid for their namelist group, and
+    /// the namelists whose group FDS does not recognise. FDS stops with an
+    /// error on either, while the decoder ignores them.
+    pub fn validate_names(&self) -> Vec<UnknownName> {
+        let mut unknown: Vec<UnknownName> = self
+            .unknown_parameters
+            .iter()
+            .map(|parameter| {
+                let candidates = catalog::group(&parameter.namelist)
+                    .map(|group| group.parameters)
+                    .unwrap_or(&[])
+                    .iter()
+                    .map(|candidate| candidate.name);
+                UnknownName::Parameter {
+                    suggestion: closest(&parameter.parameter, candidates),
+                    parameter: parameter.clone(),
+                }
+            })
+            .collect();
+        for (index, namelist) in self.unknown_namelists.iter().enumerate() {
+            let name = namelist.name.as_str();
+            if catalog::group(name).is_some() || catalog::UNCATALOGUED_GROUPS.contains(&name) {
+                continue;
+            }
+            let candidates = catalog::GROUPS
+                .iter()
+                .map(|group| group.name)
+                .chain(catalog::UNCATALOGUED_GROUPS.iter().copied());
+            unknown.push(UnknownName::Namelist {
+                index,
+                name: name.to_string(),
+                suggestion: closest(name, candidates),
+            });
+        }
+        unknown
+    }
+}
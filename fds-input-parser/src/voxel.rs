@@ -0,0 +1,143 @@
+//! The cells of a mesh which FDS will treat as solid, once OBSTs have been
+//! snapped to the grid and HOLEs cut from them.
+use crate::decode::*;
+use crate::geometry::ControlledHoles;
+use crate::grid::{CellRange, Grid};
+
+/// Which cells of a mesh are solid, and the OBST which fills each one.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Occupancy {
+    pub grid: Grid,
+    /// The number of cells along each axis.
+    pub ijk: IJK,
+    /// The index in [`FDSFile::obsts`] of the OBST filling each cell, or
+    /// [`None`] for gas. Cells are ordered with i varying fastest, then j,
+    /// then k.
+    cells: Vec<Option<usize>>,
+}
+
+impl Occupancy {
+    fn index(&self, i: GridCoord, j: GridCoord, k: GridCoord) -> Option<usize> {
+        if i < 0 || j < 0 || k < 0 || i >= self.ijk.i || j >= self.ijk.j || k >= self.ijk.k {
+            return None;
+        }
+        Some((i + self.ijk.i * (j + self.ijk.j * k)) as usize)
+    }
+
+    /// The OBST filling a cell, or [`None`] if the cell is gas or outside the
+    /// mesh.
+    pub fn obst_at(&self, i: GridCoord, j: GridCoord, k: GridCoord) -> Option<usize> {
+        self.index(i, j, k).and_then(|index| self.cells[index])
+    }
+
+    pub fn is_solid(&self, i: GridCoord, j: GridCoord, k: GridCoord) -> bool {
+        self.obst_at(i, j, k).is_some()
+    }
+
+    /// The SURF on a face of a solid cell, or [`None`] if the cell is gas or
+    /// the face is not exposed to gas within the mesh.
+    pub fn surf_at<'a>(
+        &self,
+        fds_file: &'a FDSFile,
+        i: GridCoord,
+        j: GridCoord,
+        k: GridCoord,
+        direction: Direction,
+    ) -> Option<&'a str> {
+        let obst = self.obst_at(i, j, k)?;
+        let (di, dj, dk) = match direction {
+            Direction::NegX => (-1, 0, 0),
+            Direction::PosX => (1, 0, 0),
+            Direction::NegY => (0, -1, 0),
+            Direction::PosY => (0, 1, 0),
+            Direction::NegZ => (0, 0, -1),
+            Direction::PosZ => (0, 0, 1),
+        };
+        let (ni, nj, nk) = (i + di, j + dj, k + dk);
+        if self.index(ni, nj, nk).is_none() || self.is_solid(ni, nj, nk) {
+            return None;
+        }
+        Some(fds_file.obsts[obst].surf_for_face(direction))
+    }
+
+    pub fn n_cells(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn n_solid_cells(&self) -> usize {
+        self.cells.iter().filter(|cell| cell.is_some()).count()
+    }
+
+    /// The fraction of cells which are solid.
+    pub fn solid_fraction(&self) -> f64 {
+        if self.cells.is_empty() {
+            0.0
+        } else {
+            self.n_solid_cells() as f64 / self.n_cells() as f64
+        }
+    }
+
+    /// The volume of the solid cells.
+    pub fn solid_volume(&self) -> f64 {
+        let mut volume = 0.0;
+        for k in 0..self.ijk.k {
+            for j in 0..self.ijk.j {
+                for i in 0..self.ijk.i {
+                    if self.is_solid(i, j, k) {
+                        let (i, j, k) = (i as usize, j as usize, k as usize);
+                        volume += (self.grid.x.faces[i + 1] - self.grid.x.faces[i])
+                            * (self.grid.y.faces[j + 1] - self.grid.y.faces[j])
+                            * (self.grid.z.faces[k + 1] - self.grid.z.faces[k]);
+                    }
+                }
+            }
+        }
+        volume
+    }
+}
+
+impl FDSFile {
+    /// Determine which cells of the mesh at `mesh_index` (0-based) in
+    /// [`FDSFile::meshes`] are solid. As in FDS, each OBST and each HOLE is
+    /// snapped to the (possibly stretched) grid, and the cells of the HOLEs
+    /// are then removed from those of the OBSTs which permit holes. Where
+    /// OBSTs overlap the one defined later takes the cell. An OBST with
+    /// THICKEN=.TRUE. which snaps to zero thickness along an axis occupies the
+    /// cell containing its midpoint; other OBSTs and HOLEs which snap to zero
+    /// thickness fill or cut no cells.
+    pub fn voxelize(&self, mesh_index: usize, controlled_holes: ControlledHoles) -> Occupancy {
+        let grid = self.mesh_grid(mesh_index);
+        let ijk = self.meshes[mesh_index].ijk;
+        let holes: Vec<CellRange> = self
+            .cut_holes(controlled_holes)
+            .filter_map(|hole| grid.snap(&hole.xb))
+            .collect();
+        let mut occupancy = Occupancy {
+            cells: vec![None; (ijk.i * ijk.j * ijk.k) as usize],
+            grid,
+            ijk,
+        };
+        for (obst_index, obst) in self.obsts.iter().enumerate() {
+            let range = match occupancy.grid.snap_thickened(&obst.xb, obst.thicken) {
+                Some(range) => range,
+                None => continue,
+            };
+            let holes: &[CellRange] = if obst.permit_hole { &holes } else { &[] };
+            for k in range.k1..range.k2 {
+                for j in range.j1..range.j2 {
+                    for i in range.i1..range.i2 {
+                        if holes.iter().any(|hole| hole.contains_cell(i, j, k)) {
+                            continue;
+                        }
+                        if let Some(index) = occupancy.index(i, j, k) {
+                            occupancy.cells[index] = Some(obst_index);
+                        }
+                    }
+                }
+            }
+        }
+        occupancy
+    }
+}
@@ -1,5 +1,4 @@
 use crate::decode::*;
-// TODO: everything here assumes XBs are sorted, make this true.
 
 /// A trait for objects that MUST have an XB (for example: meshes, which are
 /// given a default XB if it's not specified).
@@ -36,6 +36,1056 @@ mod integration {
         }
     }
 
+    #[test]
+    fn decode_mesh_defaults() {
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH ID='M1' /\n\
+             &MESH ID='M2', IJK=20,10,5, XB=1,3,0,1,0,0.5, MPI_PROCESS=1, CHECK_MESH_ALIGNMENT=.TRUE. /\n\
+             &TRNX MESH_NUMBER=2, CC=2.0, PC=1.5 /\n",
+        );
+        let m1 = &fds_file.meshes[0];
+        assert_eq!(m1.ijk.i, 10);
+        assert_eq!(m1.xb.x2, 1.0);
+        assert_eq!(m1.mpi_process, None);
+        let m2 = &fds_file.meshes[1];
+        assert_eq!(m2.n_cells(), 1000);
+        assert_eq!(m2.mpi_process, Some(1));
+        assert!(m2.check_mesh_alignment);
+        assert_eq!(fds_file.trnxs_for_mesh(1).len(), 0);
+        assert_eq!(fds_file.trnxs_for_mesh(2).len(), 1);
+    }
+
+    #[test]
+    fn decode_incomplete_trn() {
+        use decode::DecodeErrorKind;
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &TRNY CC=0.5 /\n\
+             &TRNZ CC=0.5, PC='high' /\n",
+        );
+        assert!(fds_file.trnys.is_empty());
+        assert!(fds_file.trnzs.is_empty());
+        let kinds: Vec<_> = fds_file.decode_errors.iter().map(|e| &e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &DecodeErrorKind::MissingParameter("PC".to_string()),
+                &DecodeErrorKind::InvalidParameter("PC".to_string())
+            ]
+        );
+        assert_eq!(
+            fds_file.decode_errors[0].to_string(),
+            "TRNY: PC is required"
+        );
+        assert_eq!(fds_file.unknown_namelists.len(), 2);
+    }
+
+    #[test]
+    fn decode_time() {
+        let fds_file =
+            parse_and_decode_fds_input("&TIME T_BEGIN=10, T_END=610., LOCK_TIME_STEP=.TRUE. /\n");
+        let time = fds_file.time.unwrap();
+        assert_eq!(time.duration(), 600.0);
+        assert!(time.lock_time_step);
+        assert!(time.restrict_time_step);
+        assert_eq!(time.dt, None);
+        assert_eq!(time.wall_increment, 2);
+
+        let fds_file = parse_and_decode_fds_input("&TIME T_END='ten' /\n");
+        assert!(fds_file.time.is_none());
+        assert_eq!(
+            fds_file.decode_errors[0].kind,
+            decode::DecodeErrorKind::InvalidParameter("T_END".to_string())
+        );
+
+        let fds_file = parse_and_decode_fds_input(
+            "&TIME T_END=10. /\n\
+             &TIME T_END=20. /\n",
+        );
+        assert_eq!(fds_file.time.unwrap().t_end, 10.0);
+        assert_eq!(
+            fds_file.decode_errors[0].kind,
+            decode::DecodeErrorKind::Repeated
+        );
+    }
+
+    #[test]
+    fn dangling_references() {
+        let fds_file = parse_and_decode_fds_input(
+            "&SURF ID='FIRE', HRRPUA=500., RAMP_Q='FIRE_RAMP' /\n\
+             &RAMP ID='FIRE_RAMP', T=0, F=0 /\n\
+             &RAMP ID='FIRE_RAMP', T=60, F=1 /\n\
+             &OBST XB=0,1,0,1,0,1, SURF_IDS='FIRE','INERT','MISSING' /\n\
+             &VENT XB=0,1,0,1,0,0, SURF_ID='OPEN', DEVC_ID='TC1' /\n\
+             &DEVC ID='SPK', XYZ=0.5,0.5,0.9, PROP_ID='K-11' /\n\
+             &DEVC ID='CO', XYZ=0.5,0.5,0.9, QUANTITY='VOLUME FRACTION', SPEC_ID='CARBON MONOXIDE' /\n\
+             &DEVC ID='HCN', XYZ=0.5,0.5,0.9, QUANTITY='VOLUME FRACTION', SPEC_ID='HCN' /\n\
+             &DEVC ID='FUEL', XYZ=0.5,0.5,0.9, QUANTITY='VOLUME FRACTION', SPEC_ID='WOOD' /\n\
+             &REAC FUEL='WOOD', SOOT_YIELD=0.01 /\n\
+             &CTRL ID='KILL', FUNCTION_TYPE='KILL', INPUT_ID='CO', LATCH=.FALSE. /\n",
+        );
+        assert!(!fds_file.ctrls[0].latch);
+        assert_eq!(fds_file.ctrls[0].on_bound, "LOWER");
+        assert_eq!(fds_file.ramps.len(), 1);
+        assert_eq!(fds_file.ramps[0].entries.len(), 2);
+        let dangling = fds_file.validate_references();
+        let ids: Vec<&str> = dangling.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["MISSING", "TC1", "K-11", "HCN"]);
+        assert_eq!(dangling[0].source.namelist, "OBST");
+        assert_eq!(dangling[0].parameter, "SURF_IDS");
+    }
+
+    #[test]
+    fn duplicate_ids() {
+        let fds_file = parse_and_decode_fds_input(
+            "&SURF ID='FIRE', HRRPUA=500. /\n\
+             &SURF ID='FIRE', HRRPUA=1000. /\n\
+             &DEVC ID='TC1', XYZ=0.5,0.5,0.9, QUANTITY='TEMPERATURE' /\n\
+             &CTRL ID='TC1', FUNCTION_TYPE='ALL', INPUT_ID='TC1' /\n",
+        );
+        let duplicates = fds_file.validate_unique_ids();
+        assert_eq!(duplicates.len(), 2);
+        assert_eq!(duplicates[0].id, "FIRE");
+        assert_eq!(duplicates[0].positions, (1, 2));
+        assert_eq!(duplicates[1].first.namelist, "DEVC");
+        assert_eq!(duplicates[1].duplicate.namelist, "CTRL");
+        assert_eq!(duplicates[1].positions, (1, 1));
+        assert_eq!(
+            duplicates[1].to_string(),
+            "CTRL #1: ID 'TC1' is already used by DEVC #1"
+        );
+
+        // Built-in SURFs ahead of the input's are not counted.
+        let mut fds_file = parse_and_decode_fds_input(
+            "&SURF ID='INERT' /\n\
+             &SURF ID='FIRE', HRRPUA=500. /\n\
+             &SURF ID='INERT' /\n\
+             &SURF ID='FIRE', HRRPUA=1000. /\n",
+        );
+        fds_file.builtin_surfs = 1;
+        let duplicates = fds_file.validate_unique_ids();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].id, "FIRE");
+        assert_eq!(duplicates[0].first.index, 1);
+        assert_eq!(duplicates[0].positions, (1, 3));
+    }
+
+    #[test]
+    fn mesh_alignment() {
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH ID='A', IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &MESH ID='B', IJK=20,20,20, XB=1,2,0,1,0,1 /\n\
+             &MESH ID='C', IJK=15,10,10, XB=0,1,1,2,0,1 /\n\
+             &MESH ID='D', IJK=10,10,10, XB=1.5,2.5,0,1,0,1 /\n",
+        );
+        let issues = fds_file.validate_meshes(validate::DEFAULT_MAX_ASPECT_RATIO);
+        assert_eq!(issues.len(), 2);
+        match &issues[0] {
+            validate::MeshIssue::CellSizeRatio { a, b, .. } => {
+                assert_eq!(a.id.as_deref(), Some("A"));
+                assert_eq!(b.id.as_deref(), Some("C"));
+            }
+            issue => panic!("unexpected issue: {}", issue),
+        }
+        match &issues[1] {
+            validate::MeshIssue::Overlap { volume, .. } => assert!((volume - 0.5).abs() < 1e-9),
+            issue => panic!("unexpected issue: {}", issue),
+        }
+    }
+
+    #[test]
+    fn objects_outside_meshes() {
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &OBST ID='Inside', XB=0.2,0.4,0.2,0.4,0,0.5 /\n\
+             &OBST ID='Partial', XB=0.8,1.2,0.2,0.4,0,0.5 /\n\
+             &VENT ID='Outside', XB=2,3,0,1,0,0, SURF_ID='OPEN' /\n\
+             &VENT ID='Boundary', XB=1,1,0,1,0,1, SURF_ID='OPEN' /\n\
+             &DEVC ID='TC', XYZ=0.5,0.5,1.5, QUANTITY='TEMPERATURE' /\n\
+             &SLCF PBY=0.5, QUANTITY='TEMPERATURE' /\n",
+        );
+        let uncovered = fds_file.validate_mesh_coverage();
+        let ids: Vec<&str> = uncovered
+            .iter()
+            .map(|u| u.object.id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["Partial", "Outside", "TC"]);
+        match &uncovered[0].coverage {
+            validate::Coverage::Partial { clipped } => {
+                assert_eq!(clipped.len(), 1);
+                assert_eq!(clipped[0].x1, 1.0);
+                assert_eq!(clipped[0].x2, 1.2);
+            }
+            coverage => panic!("unexpected coverage: {:?}", coverage),
+        }
+        assert_eq!(uncovered[1].coverage, validate::Coverage::Outside);
+    }
+
+    #[test]
+    fn vent_placement() {
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &MESH IJK=10,10,10, XB=1,2,0,1,0,1 /\n\
+             &SURF ID='SUPPLY', VEL=-1.0 /\n\
+             &OBST XB=0.2,0.4,0.2,0.4,0,0.5 /\n\
+             &VENT ID='OnObst', XB=0.2,0.4,0.2,0.4,0.5,0.5, SURF_ID='SUPPLY' /\n\
+             &VENT ID='Floor', XB=0.5,1.5,0,1,0,0, SURF_ID='INERT' /\n\
+             &VENT ID='Floating', XB=0.6,0.8,0.6,0.8,0.5,0.5, SURF_ID='SUPPLY' /\n\
+             &VENT ID='Box', XB=0.6,0.8,0.6,0.8,0.5,0.6, SURF_ID='SUPPLY' /\n",
+        );
+        let issues = fds_file.validate_vents();
+        assert_eq!(issues.len(), 3);
+        match &issues[0] {
+            validate::VentIssue::Straddling { vent, meshes } => {
+                assert_eq!(vent.id.as_deref(), Some("Floor"));
+                assert_eq!(meshes.len(), 2);
+            }
+            issue => panic!("unexpected issue: {}", issue),
+        }
+        match &issues[1] {
+            validate::VentIssue::Floating {
+                vent,
+                unsupported_area,
+            } => {
+                assert_eq!(vent.id.as_deref(), Some("Floating"));
+                assert!((unsupported_area - 0.04).abs() < 1e-9);
+            }
+            issue => panic!("unexpected issue: {}", issue),
+        }
+        match &issues[2] {
+            validate::VentIssue::NonPlanar { vent } => {
+                assert_eq!(vent.id.as_deref(), Some("Box"))
+            }
+            issue => panic!("unexpected issue: {}", issue),
+        }
+    }
+
+    #[test]
+    fn sub_grid_objects() {
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &OBST ID='Wall', XB=0.5,0.52,0,1,0,1 /\n\
+             &OBST ID='Thickened', XB=0.5,0.52,0,1,0,1, THICKEN=.TRUE. /\n\
+             &OBST ID='Straddle', XB=0.14,0.16,0,1,0,1 /\n\
+             &OBST ID='Offset', XB=0.76,0.78,0,1,0,1, THICKEN=.TRUE. /\n\
+             &VENT ID='Slot', XB=0,0.3,0.41,0.43,0,0, SURF_ID='OPEN' /\n",
+        );
+        let sub_grid = fds_file.validate_sub_grid();
+        let found: Vec<(&str, validate::SubGridSnap)> = sub_grid
+            .iter()
+            .map(|s| (s.object.id.as_deref().unwrap(), s.snap))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("Wall", validate::SubGridSnap::ZeroThickness),
+                ("Thickened", validate::SubGridSnap::OneCell),
+                ("Straddle", validate::SubGridSnap::OneCell),
+                ("Offset", validate::SubGridSnap::OneCell),
+                ("Slot", validate::SubGridSnap::ZeroThickness),
+            ]
+        );
+        // Both bounds of the thickened OBST round to x = 0.8, but it fills the
+        // cell containing it, as in voxelize.
+        let (lower, upper) = sub_grid[3].snapped;
+        assert!((lower - 0.7).abs() < 1e-9 && (upper - 0.8).abs() < 1e-9);
+        assert_eq!(sub_grid[4].axis, decode::Plane::Y);
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &TRNX CC=0.5, PC=0.3 /\n",
+        );
+        let xb = decode::XB::new(0.14, 0.36, 0.0, 1.0, 0.5, 0.5);
+        let uniform = fds_file.meshes[0].grid();
+        let range = uniform.snap(&xb).unwrap();
+        assert_eq!(
+            range,
+            grid::CellRange {
+                i1: 1,
+                i2: 4,
+                j1: 0,
+                j2: 10,
+                k1: 5,
+                k2: 5,
+            }
+        );
+        let snapped = uniform.cell_range_to_xb(&range).unwrap();
+        assert!((snapped.x1 - 0.1).abs() < 1e-9);
+        assert!((snapped.x2 - 0.4).abs() < 1e-9);
+        // Stretching puts half the cells below x = 0.3.
+        let range = fds_file.snap(0, &xb).unwrap();
+        assert_eq!((range.i1, range.i2), (2, 5));
+        let snapped = fds_file.cell_range_to_xb(0, &range).unwrap();
+        assert!((snapped.x2 - 0.3).abs() < 1e-9);
+        let mesh = &fds_file.meshes[0];
+        let trnx = fds_file.trnxs_for_mesh(1);
+        assert_eq!(mesh.snap(&xb, &trnx, &[], &[]), Some(range));
+        assert_eq!(mesh.snap(&xb, &[], &[], &[]), uniform.snap(&xb));
+        let snapped = mesh.cell_range_to_xb(&range, &trnx, &[], &[]).unwrap();
+        assert!((snapped.x2 - 0.3).abs() < 1e-9);
+        let outside = decode::XB::new(1.5, 2.0, 0.0, 1.0, 0.5, 0.5);
+        assert_eq!(fds_file.snap(0, &outside), None);
+        let beyond = grid::CellRange { i2: 11, ..range };
+        assert_eq!(fds_file.cell_range_to_xb(0, &beyond), None);
+    }
+
+    #[test]
+    fn spatial_queries() {
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH ID='Left', IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &MESH ID='Right', IJK=10,10,10, XB=1,2,0,1,0,1 /\n\
+             &OBST ID='Table', XB=0.2,0.4,0.2,0.4,0,0.5 /\n\
+             &OBST ID='Shelf', XB=1.5,1.9,0,0.2,0,0.8 /\n\
+             &HOLE ID='Door', XB=1.6,1.7,0,0.2,0,0.5 /\n\
+             &VENT ID='Floor', XB=0,2,0,1,0,0, SURF_ID='INERT' /\n\
+             &DEVC ID='Probe', XYZ=1.2,0.5,0.5, QUANTITY='TEMPERATURE' /\n",
+        );
+        let index = fds_file.spatial_index();
+        let xb = decode::XB::new(1.55, 1.65, 0.1, 0.1, 0.1, 0.1);
+        let found: Vec<(spatial::ObjectKind, usize)> = index
+            .intersecting(&xb)
+            .iter()
+            .map(|entry| (entry.kind, entry.index))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (spatial::ObjectKind::Obst, 1),
+                (spatial::ObjectKind::Mesh, 1),
+                (spatial::ObjectKind::Hole, 0),
+            ]
+        );
+        let point = decode::XYZ {
+            x: 0.3,
+            y: 0.3,
+            z: 0.0,
+        };
+        assert_eq!(index.containing(point).len(), 3);
+        let (nearest, distance) = index.nearest(point, spatial::ObjectKind::Devc).unwrap();
+        assert_eq!(nearest.index, 0);
+        assert!((distance - (0.81f64 + 0.04 + 0.25).sqrt()).abs() < 1e-9);
+        assert!(index.nearest(point, spatial::ObjectKind::Obst).unwrap().1 == 0.0);
+    }
+
+    #[test]
+    fn normalized_xbs() {
+        let fds_file = parse_and_decode_fds_input(
+            "&OBST ID='Reversed', XB=1,0,0,2,3,1 /\n\
+             &VENT ID='Wall', XB=0,0,0,1,0,1, SURF_ID='OPEN' /\n",
+        );
+        let xb = fds_file.obsts[0].xb;
+        assert_eq!(xb, decode::XB::new(0.0, 1.0, 0.0, 2.0, 1.0, 3.0));
+        assert_eq!((xb.x1, xb.x2, xb.z1, xb.z2), (0.0, 1.0, 1.0, 3.0));
+        assert!(xb.reversed.x && !xb.reversed.y && xb.reversed.z);
+        let original = xb.original();
+        assert_eq!((original.x1, original.x2), (1.0, 0.0));
+        assert_eq!(xb.volume(), 4.0);
+        assert!(!xb.is_degenerate());
+        assert_eq!(xb.plane(), None);
+        let center = xb.center();
+        assert_eq!((center.x, center.y, center.z), (0.5, 1.0, 2.0));
+
+        let wall = fds_file.vents[0].xb.unwrap();
+        assert!(wall.is_degenerate());
+        assert_eq!(wall.plane(), Some(decode::Plane::X));
+        assert_eq!(
+            xb.intersection(&wall),
+            Some(decode::XB::new(0.0, 0.0, 0.0, 1.0, 1.0, 1.0))
+        );
+        assert_eq!(
+            xb.union(&wall),
+            decode::XB::new(0.0, 1.0, 0.0, 2.0, 0.0, 3.0)
+        );
+        let far = decode::XB::new(5.0, 6.0, 0.0, 1.0, 0.0, 1.0);
+        assert_eq!(xb.intersection(&far), None);
+        assert!(xb.contains_point(decode::XYZ {
+            x: 1.0,
+            y: 0.5,
+            z: 3.0
+        }));
+    }
+
+    #[test]
+    fn obst_face_surfaces() {
+        use decode::Direction;
+        let fds_file = parse_and_decode_fds_input(
+            "&SURF ID='FIRE', HRRPUA=500 /\n\
+             &OBST ID='Plain', XB=0,1,0,2,0,3 /\n\
+             &OBST ID='Burner', XB=0,1,0,2,0,3, SURF_IDS='FIRE','STEEL','INERT', SURF_ID='STEEL' /\n\
+             &OBST ID='Six', XB=0,1,0,2,0,3, SURF_ID6='A','B','C','D','E','F', SURF_IDS='FIRE','STEEL','INERT' /\n",
+        );
+        let plain = &fds_file.obsts[0];
+        assert_eq!(plain.surf_for_face(Direction::PosZ), "INERT");
+        let burner = &fds_file.obsts[1];
+        assert_eq!(burner.surf_for_face(Direction::PosZ), "FIRE");
+        assert_eq!(burner.surf_for_face(Direction::NegX), "STEEL");
+        assert_eq!(burner.surf_for_face(Direction::NegZ), "INERT");
+        assert_eq!(burner.surf_area("FIRE"), 2.0);
+        assert_eq!(burner.surf_area("STEEL"), 2.0 * (6.0 + 3.0));
+        assert!(burner.is_burner(&fds_file));
+        let six = &fds_file.obsts[2];
+        assert_eq!(six.surf_for_face(Direction::PosY), "D");
+        assert_eq!(six.face_area(Direction::PosY), 3.0);
+        assert!(!six.has_surf("FIRE"));
+        assert!(!six.is_burner(&fds_file));
+    }
+
+    #[test]
+    fn hole_subtraction() {
+        use geometry::ControlledHoles;
+        let fds_file = parse_and_decode_fds_input(
+            "&OBST ID='Wall', XB=0,4,0,0.2,0,3 /\n\
+             &OBST ID='Glass', XB=0,4,1,1.2,0,3, PERMIT_HOLE=.FALSE. /\n\
+             &HOLE ID='Door', XB=1,2,-0.1,1.3,0,2 /\n\
+             &HOLE ID='Hatch', XB=3,3.5,-0.1,0.3,2,2.5, DEVC_ID='Timer' /\n",
+        );
+        let volume = |boxes: &[geometry::SolidBox], id: &str| -> f64 {
+            boxes
+                .iter()
+                .filter(|b| b.id.as_deref() == Some(id))
+                .map(|b| b.xb.volume())
+                .sum()
+        };
+        let closed = fds_file.solid_boxes(ControlledHoles::Closed);
+        assert!((volume(&closed, "Wall") - (2.4 - 0.4)).abs() < 1e-9);
+        assert!((volume(&closed, "Glass") - 2.4).abs() < 1e-9);
+        assert!(closed
+            .iter()
+            .all(|b| b.id.as_deref() != Some("Wall") || b.obst_index == 0));
+        let open = fds_file.solid_boxes(ControlledHoles::Open);
+        assert!((volume(&open, "Wall") - (2.4 - 0.4 - 0.05)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn voxelize_mesh() {
+        use decode::Direction;
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &SURF ID='FIRE', HRRPUA=500 /\n\
+             &OBST ID='Block', XB=0,0.5,0,0.5,0,0.48, SURF_IDS='FIRE','INERT','INERT' /\n\
+             &OBST ID='Sheet', XB=0.7,0.7,0,1,0,1, THICKEN=.TRUE. /\n\
+             &HOLE XB=0,0.2,0,0.2,0,1 /\n",
+        );
+        let occupancy = fds_file.voxelize(0, geometry::ControlledHoles::Open);
+        assert_eq!(occupancy.n_cells(), 1000);
+        assert_eq!(occupancy.n_solid_cells(), 5 * 5 * 5 - 2 * 2 * 5 + 10 * 10);
+        assert!((occupancy.solid_fraction() - 0.205).abs() < 1e-9);
+        assert_eq!(occupancy.obst_at(7, 3, 3), Some(1));
+        assert!(!occupancy.is_solid(0, 0, 0));
+        assert_eq!(
+            occupancy.surf_at(&fds_file, 4, 4, 4, Direction::PosZ),
+            Some("FIRE")
+        );
+        assert_eq!(occupancy.surf_at(&fds_file, 4, 4, 3, Direction::PosZ), None);
+        assert_eq!(
+            occupancy.surf_at(&fds_file, 2, 1, 0, Direction::NegX),
+            Some("INERT")
+        );
+        // The HOLE is snapped to the grid as a whole, so it removes the row of
+        // cells at j = 0 which its XB only partly covers.
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &OBST XB=0.7,0.7,0,1,0,1, THICKEN=.TRUE. /\n\
+             &HOLE XB=0.6,0.8,0.04,1,0,1 /\n",
+        );
+        let occupancy = fds_file.voxelize(0, geometry::ControlledHoles::Open);
+        assert_eq!(occupancy.n_solid_cells(), 0);
+        // A thickened sheet off the cell faces fills the cell containing it,
+        // not the one above the face it snaps to.
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &OBST XB=0.76,0.76,0,1,0,1, THICKEN=.TRUE. /\n",
+        );
+        let occupancy = fds_file.voxelize(0, geometry::ControlledHoles::Open);
+        assert_eq!(occupancy.n_solid_cells(), 100);
+        assert!(occupancy.is_solid(7, 0, 0));
+        assert!(!occupancy.is_solid(8, 0, 0));
+    }
+
+    #[test]
+    fn cost_estimate() {
+        let fds_file = parse_and_decode_fds_input(
+            "&TIME T_END=60 /\n\
+             &MESH IJK=10,10,10, XB=0,1,0,1,0,1, MPI_PROCESS=0 /\n\
+             &MESH IJK=20,20,20, XB=1,2,0,1,0,1, MPI_PROCESS=1 /\n\
+             &MESH IJK=10,10,10, XB=0,1,1,2,0,1, MPI_PROCESS=0 /\n",
+        );
+        let estimate = fds_file
+            .estimate_cost(&cost::CostParameters::default())
+            .unwrap();
+        assert_eq!(estimate.n_cells, 10_000);
+        assert_eq!(estimate.processes.len(), 2);
+        assert_eq!(estimate.processes[0].meshes, vec![0, 2]);
+        assert_eq!(estimate.processes[0].n_cells, 2_000);
+        assert_eq!(estimate.processes[1].n_cells, 8_000);
+        assert!((estimate.memory() - 1e7).abs() < 1e-3);
+        assert!((estimate.time_step - 0.01).abs() < 1e-12);
+        assert!((estimate.n_time_steps as i64 - 6_000).abs() <= 1);
+        assert!((estimate.wall_clock(1e6) - 48.0).abs() < 0.01);
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH IJK=10,10,10, XB=0,1,0,1,0,1, MPI_PROCESS=0 /\n\
+             &MESH IJK=10,10,10, XB=1,2,0,1,0,1 /\n",
+        );
+        assert_eq!(
+            fds_file.mpi_processes(),
+            Err(cost::MpiProcessError::Mixed {
+                with: vec![0],
+                without: vec![1],
+            })
+        );
+    }
+
+    #[test]
+    fn mpi_load_balance() {
+        let mut fds_file = parse_and_decode_fds_input(
+            "&MESH ID='C', IJK=10,10,10, XB=2,3,0,1,0,1 /\n\
+             &MESH ID='A', IJK=20,10,10, XB=0,1,0,1,0,1 /\n\
+             &MESH ID='B', IJK=10,10,10, XB=1,2,0,1,0,1 /\n\
+             &MESH ID='D', IJK=10,10,10, XB=3,4,0,1,0,1 /\n\
+             &TRNX MESH_NUMBER=2, CC=0.5, PC=0.4 /\n\
+             &SLCF PBZ=0.5, QUANTITY='TEMPERATURE', MESH_NUMBER=1 /\n",
+        );
+        assert_eq!(fds_file.mpi_loads(), Ok(vec![1000, 2000, 1000, 1000]));
+        assert_eq!(
+            fds_file.balance_mpi(0),
+            Err(balance::LoadBalanceError::NoProcesses)
+        );
+        let balance = fds_file.balance_mpi(2).unwrap();
+        assert_eq!(balance.order, vec![1, 2, 0, 3]);
+        assert_eq!(balance.mpi_processes, vec![1, 0, 0, 1]);
+        assert_eq!(balance.loads, vec![3000, 2000]);
+        assert!((balance.imbalance() - 1.2).abs() < 1e-9);
+        fds_file.apply_load_balance(&balance);
+        let ids: Vec<&str> = fds_file
+            .meshes
+            .iter()
+            .map(|mesh| mesh.id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["A", "B", "C", "D"]);
+        assert_eq!(fds_file.meshes[1].mpi_process, Some(0));
+        assert_eq!(fds_file.trnxs[0].mesh_number, 1);
+        assert_eq!(fds_file.slcfs[0].mesh_number, Some(3));
+        assert_eq!(fds_file.mpi_loads(), Ok(vec![3000, 2000]));
+        // A C-shaped domain, whose two arms are only joined through G. Each
+        // process gets a connected set of meshes.
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH ID='A', IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &MESH ID='B', IJK=10,10,10, XB=1,2,0,1,0,1 /\n\
+             &MESH ID='C', IJK=10,10,10, XB=2,3,0,1,0,1 /\n\
+             &MESH ID='D', IJK=10,10,10, XB=0,1,2,3,0,1 /\n\
+             &MESH ID='E', IJK=10,10,10, XB=1,2,2,3,0,1 /\n\
+             &MESH ID='F', IJK=10,10,10, XB=2,3,2,3,0,1 /\n\
+             &MESH ID='G', IJK=10,10,10, XB=2,3,1,2,0,1 /\n",
+        );
+        let balance = fds_file.balance_mpi(2).unwrap();
+        assert_eq!(balance.mpi_processes, vec![0, 0, 0, 1, 1, 1, 0]);
+        assert_eq!(balance.order, vec![0, 1, 2, 6, 3, 4, 5]);
+        assert_eq!(balance.loads, vec![4000, 3000]);
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH IJK=10,10,10, XB=0,1,0,1,0,1, MPI_PROCESS=0 /\n\
+             &MESH IJK=10,10,10, XB=1,2,0,1,0,1, MPI_PROCESS=-1 /\n",
+        );
+        assert_eq!(
+            fds_file.mpi_loads(),
+            Err(cost::MpiProcessError::Negative {
+                mesh: 1,
+                mpi_process: -1,
+            })
+        );
+    }
+
+    #[test]
+    fn split_and_merge_meshes() {
+        let mut fds_file = parse_and_decode_fds_input(
+            "&MESH ID='M', IJK=10,4,4, XB=0,1,0,0.4,0,0.4 /\n\
+             &MESH ID='N', IJK=10,4,4, XB=0,1,0.4,0.8,0,0.4 /\n\
+             &TRNX MESH_NUMBER=2, CC=0.5, PC=0.4 /\n\
+             &OBST ID='Box', XB=0.7,0.9,0,0.1,0,0.1, MESH_ID='M' /\n\
+             &SLCF PBY=0.6, QUANTITY='TEMPERATURE', MESH_NUMBER=2 /\n",
+        );
+        assert_eq!(
+            fds_file.split_mesh(1, 2, 1, 1),
+            Err(mesh::MeshEditError::Stretched(1))
+        );
+        assert_eq!(
+            fds_file.split_mesh(0, 11, 1, 1),
+            Err(mesh::MeshEditError::InvalidSplit {
+                ni: 11,
+                nj: 1,
+                nk: 1
+            })
+        );
+        assert_eq!(fds_file.meshes.len(), 2);
+        fds_file.split_mesh(0, 3, 1, 1).unwrap();
+        let summary: Vec<(&str, i64)> = fds_file
+            .meshes
+            .iter()
+            .map(|mesh| (mesh.id.as_deref().unwrap(), mesh.ijk.i))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("M_1_1_1", 3), ("M_2_1_1", 3), ("M_3_1_1", 4), ("N", 10)]
+        );
+        assert!((fds_file.meshes[2].xb.x1 - 0.6).abs() < 1e-12);
+        assert_eq!(fds_file.meshes[1].xb.x2, fds_file.meshes[2].xb.x1);
+        assert!(fds_file
+            .meshes
+            .iter()
+            .all(|mesh| (mesh.resolution().x - 0.1).abs() < 1e-12));
+        assert_eq!(fds_file.obsts[0].mesh_id.as_deref(), Some("M_3_1_1"));
+        assert_eq!(fds_file.trnxs[0].mesh_number, 4);
+        assert_eq!(fds_file.slcfs[0].mesh_number, Some(4));
+
+        assert_eq!(
+            fds_file.merge_meshes(0, 2),
+            Err(mesh::MeshEditError::NotMergeable(0, 2))
+        );
+        assert_eq!(
+            fds_file.merge_meshes(1, 3),
+            Err(mesh::MeshEditError::Stretched(3))
+        );
+        fds_file.merge_meshes(1, 2).unwrap();
+        let merged = &fds_file.meshes[1];
+        assert_eq!(merged.id.as_deref(), Some("M_2_1_1"));
+        assert_eq!(merged.ijk.i, 7);
+        assert_eq!(merged.xb.x2, 1.0);
+        assert_eq!(fds_file.obsts[0].mesh_id.as_deref(), Some("M_2_1_1"));
+        assert_eq!(fds_file.trnxs[0].mesh_number, 3);
+        assert_eq!(fds_file.slcfs[0].mesh_number, Some(3));
+    }
+
+    #[test]
+    fn rescale_meshes() {
+        let base = parse_and_decode_fds_input(
+            "&MESH IJK=20,10,6, XB=0,2,0,1,0,0.6 /\n\
+             &MESH IJK=10,10,6, XB=2,4,0,1,0,0.6 /\n\
+             &OBST ID='Beam', XB=0,4,0.45,0.6,0.5,0.6 /\n",
+        );
+        let mut fine = base.clone();
+        let rescaled = fine.rescale_meshes(2.0);
+        assert_eq!(rescaled.factors, [2.0, 2.0, 2.0]);
+        assert_eq!((fine.meshes[0].ijk.i, fine.meshes[1].ijk.i), (40, 20));
+        assert!(rescaled.sub_grid.is_empty());
+
+        let mut coarse = base.clone();
+        let rescaled = coarse.rescale_meshes(0.5);
+        // The IJK along z have a common divisor of 6, so are exactly halved,
+        // but along x it is 10, which is halved while keeping the 2:1 ratio.
+        assert_eq!(rescaled.factors, [0.5, 0.5, 0.5]);
+        assert_eq!((coarse.meshes[0].ijk.i, coarse.meshes[1].ijk.i), (10, 5));
+        assert_eq!(coarse.meshes[0].ijk.k, 3);
+        let axes: Vec<decode::Plane> = rescaled.sub_grid.iter().map(|s| s.axis).collect();
+        assert_eq!(
+            axes,
+            vec![
+                decode::Plane::Y,
+                decode::Plane::Z,
+                decode::Plane::Y,
+                decode::Plane::Z
+            ]
+        );
+    }
+
+    #[test]
+    fn fire_resolution() {
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH ID='Coarse', IJK=10,10,10, XB=0,2,0,2,0,2 /\n\
+             &MESH ID='Fine', IJK=20,20,20, XB=2,4,0,2,0,2 /\n\
+             &MISC TMPA=20, P_INF=101325 /\n\
+             &SURF ID='FIRE', HRRPUA=1000, RAMP_Q='Growth' /\n\
+             &RAMP ID='Growth', T=0, F=0 /\n\
+             &RAMP ID='Growth', T=60, F=0.5 /\n\
+             &RAMP ID='Growth', T=120, F=0.25 /\n\
+             &OBST ID='Sofa', XB=1,3,0,1,0,0.5, SURF_IDS='FIRE','INERT','INERT' /\n\
+             &VENT ID='Pool', XB=2.5,3.5,1,2,0,0, SURF_ID='FIRE' /\n",
+        );
+        let fires = fds_file.fires();
+        assert_eq!(fires.len(), 2);
+        assert_eq!(fires[0].area, 2.0);
+        assert!((fires[0].peak_hrr - 1000.0).abs() < 1e-9);
+        assert!((fires[1].peak_hrr - 500.0).abs() < 1e-9);
+
+        let ambient = fire::Ambient::from_misc(fds_file.misc.as_ref());
+        assert!((ambient.density - 1.204).abs() < 1e-3);
+        assert!((ambient.characteristic_diameter(1000.0) - 0.959).abs() < 1e-3);
+
+        let resolution = fds_file.fire_resolution();
+        let summary: Vec<(&str, &str)> = resolution
+            .iter()
+            .map(|r| (r.fire.id.as_deref().unwrap(), r.mesh.id.as_deref().unwrap()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("Sofa", "Coarse"), ("Sofa", "Fine"), ("Pool", "Fine")]
+        );
+        assert!((resolution[1].cells_per_diameter() - 9.59).abs() < 0.01);
+        assert!(fds_file.unresolved_fires().is_empty());
+
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH IJK=10,10,10, XB=0,2,0,2,0,2 /\n\
+             &SURF ID='POOL', MLRPUA=0.02 /\n\
+             &VENT ID='Pool', XB=0.5,1.5,0.5,1.5,0,0, SURF_ID='POOL' /\n",
+        );
+        assert!(fds_file.fires().is_empty());
+        let unresolved = fds_file.unresolved_fires();
+        assert_eq!(unresolved.len(), 1);
+        assert_eq!(unresolved[0].id.as_deref(), Some("Pool"));
+
+        let fds_file = parse_and_decode_fds_input(
+            "&MISC TMPA=30 /\n\
+             &MISC TMPA='hot' /\n",
+        );
+        assert_eq!(fds_file.misc.unwrap().tmpa, 30.0);
+        assert_eq!(
+            fds_file.decode_errors[0].kind,
+            decode::DecodeErrorKind::Repeated
+        );
+    }
+
+    #[test]
+    fn detector_report() {
+        use detection::DetectorKind;
+        let fds_file = parse_and_decode_fds_input(
+            "&MESH IJK=40,40,30, XB=0,4,0,4,0,3 /\n\
+             &SURF ID='FIRE', HRRPUA=1000 /\n\
+             &OBST ID='Burner', XB=0.5,1.5,1.5,2.5,0,0.5, SURF_IDS='FIRE','INERT','INERT' /\n\
+             &OBST ID='Beam', XB=2.5,3.5,0,0.5,2.95,3 /\n\
+             &PROP ID='Head', QUANTITY='SPRINKLER LINK TEMPERATURE', RTI=100, ACTIVATION_TEMPERATURE=68 /\n\
+             &PROP ID='Smoke', QUANTITY='CHAMBER OBSCURATION' /\n\
+             &DEVC ID='S1', XYZ=3,2,2.9, PROP_ID='Head' /\n\
+             &DEVC ID='S2', XYZ=3,0.2,2.9, PROP_ID='Head' /\n\
+             &DEVC ID='Heat', XYZ=1,2,2.9, QUANTITY='LINK TEMPERATURE' /\n\
+             &DEVC ID='SD', XYZ=2,2,2.9, PROP_ID='Smoke' /\n\
+             &DEVC ID='TC', XYZ=2,2,1, QUANTITY='TEMPERATURE' /\n",
+        );
+        let report = fds_file.detector_report();
+        let kinds: Vec<(&str, DetectorKind)> = report
+            .iter()
+            .map(|d| (d.devc.id.as_deref().unwrap(), d.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("S1", DetectorKind::Sprinkler),
+                ("S2", DetectorKind::Sprinkler),
+                ("Heat", DetectorKind::HeatDetector),
+                ("SD", DetectorKind::SmokeDetector),
+            ]
+        );
+        let s1 = &report[0];
+        let (nearest, spacing) = s1.nearest.clone().unwrap();
+        assert_eq!(nearest.id.as_deref(), Some("S2"));
+        assert!((spacing - 1.8).abs() < 1e-9);
+        assert!((s1.distance_to_ceiling.unwrap() - 0.1).abs() < 1e-9);
+        assert!((report[1].distance_to_ceiling.unwrap() - 0.05).abs() < 1e-9);
+        let exposure = &s1.exposures[0];
+        assert_eq!(exposure.fire.id.as_deref(), Some("Burner"));
+        assert!((exposure.radial_distance - 2.0).abs() < 1e-9);
+        assert!((exposure.ceiling_height - 2.5).abs() < 1e-9);
+        assert!((exposure.activation_time.unwrap() - 33.2).abs() < 0.1);
+        assert_eq!(report[2].rti, Some(5.0));
+        assert!(report[2].exposures[0].activation_time.unwrap() < 1.0);
+        assert_eq!(report[3].activation_obscuration, Some(3.24));
+        assert_eq!(report[3].exposures[0].activation_time, None);
+    }
+
+    /// Decoding must never write to stdout, as the library is used by tools
+    /// which write their own output there. The test binary is run again to
+    /// decode in a child process whose stdout can be inspected.
+    #[test]
+    fn decoding_writes_nothing_to_stdout() {
+        const CHILD: &str = "FDS_INPUT_PARSER_STDOUT_CHILD";
+        const START: &str = "<<decode start>>";
+        const END: &str = "<<decode end>>";
+        if std::env::var_os(CHILD).is_some() {
+            print!("{}", START);
+            let fds_file = parse_and_decode_fds_input(
+                "&HEAD CHID='quiet' /\n\
+                 &MESH IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+                 &PROP ID='Head', QUANTITY='SPRINKLER LINK TEMPERATURE' /\n\
+                 &DEVC ID='S1', XYZ=0.5,0.5,0.9, PROP_ID='Head' /\n\
+                 &DEVC ID='S2', XYZ=0.5,0.5,0.9, PROP_ID='Missing' /\n\
+                 &UNKN X=1 /\n",
+            );
+            for devc in &fds_file.devcs {
+                devc.is_sprinkler(&fds_file);
+            }
+            print!("{}", END);
+            return;
+        }
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([
+                "integration::decoding_writes_nothing_to_stdout",
+                "--exact",
+                "--nocapture",
+                "--test-threads=1",
+            ])
+            .env(CHILD, "1")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let start = stdout.find(START).expect("child did not run") + START.len();
+        let end = stdout.find(END).expect("child did not finish");
+        assert_eq!(&stdout[start..end], "");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_round_trip() {
+        let fds_file = parse_and_decode_fds_input(
+            "&HEAD CHID='json' /\n\
+             &MESH IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &SURF ID='FIRE', HRRPUA=500 /\n\
+             &OBST ID='Wall', XB=1,0,0,1,0,0.5, SURF_ID='FIRE' /\n\
+             &RADI NUMBER_RADIATION_ANGLES=104, RADIATIVE_FRACTION=0.3, ANGLE_INCREMENT(1)=2, TITLE='It''s' /\n",
+        );
+        let json = fds_file.to_json();
+        assert!(json.contains("\"unknown_namelists\""));
+        assert!(json.contains("\"RADI\""));
+        let decoded = FDSFile::from_json(&json).unwrap();
+        assert_eq!(decoded.obsts[0].xb, fds_file.obsts[0].xb);
+        assert!(decoded.obsts[0].xb.reversed.x);
+        assert_eq!(decoded.surfs[0].hrrpua, Some(500.0));
+        let radi = &decoded.unknown_namelists[0];
+        assert_eq!(radi.name, "RADI");
+        assert_eq!(radi.parameters.len(), 4);
+        assert_eq!(decoded.to_json(), json);
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn json_schema() {
+        let schema: serde_json::Value = serde_json::from_str(&FDSFile::json_schema()).unwrap();
+        let definitions = &schema["definitions"];
+        for name in &[
+            "Obst",
+            "Surf",
+            "Mesh",
+            "Vent",
+            "Devc",
+            "XB",
+            "UnknownNamelist",
+        ] {
+            assert!(definitions[name].is_object(), "{} is not defined", name);
+        }
+        let hrrpua = &definitions["Surf"]["properties"]["hrrpua"];
+        assert!(hrrpua["description"].as_str().unwrap().contains("kW/m²"));
+        assert_eq!(definitions["Time"]["properties"]["t_end"]["default"], 1.0);
+        let dx = &definitions["Mult"]["properties"]["dx"];
+        assert!(dx["description"].as_str().unwrap().ends_with("Units: m."));
+        assert_eq!(dx["default"], 0.0);
+        assert!(definitions["Mult"]["required"].is_null());
+        assert!(definitions["Slcf"]["required"].is_null());
+        assert_eq!(definitions["Obst"]["required"], serde_json::json!(["xb"]));
+        assert_eq!(
+            definitions["Obst"]["properties"]["permit_hole"]["default"],
+            true
+        );
+        assert_eq!(
+            definitions["Trnx"]["required"],
+            serde_json::json!(["cc", "pc"])
+        );
+    }
+
+    #[test]
+    fn parameter_catalog() {
+        use fds_input_parser::catalog::{self, FdsVersion, Kind, Shape, Value};
+        for group in catalog::GROUPS {
+            for pair in group.parameters.windows(2) {
+                assert!(pair[0].name < pair[1].name, "{} is not sorted", group.name);
+            }
+        }
+        let hrrpua = catalog::parameter("SURF", "HRRPUA").unwrap();
+        assert_eq!(hrrpua.kind, Kind::Real);
+        assert_eq!(hrrpua.shape, Shape::Scalar);
+        assert_eq!(hrrpua.units, Some("kW/m²"));
+        assert!(hrrpua.versions.accepts(FdsVersion::new(6, 7)));
+        let simulation_mode = catalog::parameter("MISC", "SIMULATION_MODE").unwrap();
+        assert!(!simulation_mode.versions.accepts(FdsVersion::new(6, 0)));
+        assert!(simulation_mode.versions.accepts(FdsVersion::new(6, 1)));
+        let dns = catalog::parameter("MISC", "DNS").unwrap();
+        assert!(dns.versions.accepts(FdsVersion::new(6, 0)));
+        assert!(!dns.versions.accepts(FdsVersion::new(6, 1)));
+        assert!(catalog::parameter("OBST", "SAWTOOTH").is_some());
+        assert!(catalog::UNCATALOGUED_GROUPS.contains(&"EVAC"));
+        let xb = catalog::parameter("MESH", "XB").unwrap();
+        assert_eq!(xb.shape, Shape::Array(6));
+        assert_eq!(
+            xb.default,
+            Some(Value::Reals(&[0.0, 1.0, 0.0, 1.0, 0.0, 1.0]))
+        );
+        assert_eq!(catalog::parameter("SURF", "HRRPAU"), None);
+        assert!(catalog::group("ZZZZ").is_none());
+        // The decoder takes its defaults from the catalog.
+        assert_eq!(
+            catalog::parameter("TIME", "T_END").unwrap().default,
+            Some(Value::Real(decode::Time::default().t_end))
+        );
+        assert_eq!(decode::Mesh::default().ijk.i, 10);
+        let fds_file = parse_and_decode_fds_input(
+            "&MULT ID='m', DX=1, I_UPPER=2 /\n&RAMP ID='r', T=0, F=0 /\n",
+        );
+        assert_eq!(fds_file.mults[0].dy, 0.0);
+        assert_eq!(fds_file.mults[0].i_lower, 0);
+        assert!(fds_file.mults[0].dxb.is_empty());
+        assert_eq!(
+            fds_file.ramps[0].entries[0].number_interpolation_points,
+            5000
+        );
+    }
+
+    #[test]
+    fn unknown_names() {
+        let fds_file = parse_and_decode_fds_input(
+            "&HEAD CHID='names' /\n\
+             &MESH IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &SURF ID='FIRE', HRRPAU=500 /\n\
+             &OBST XB=0,1,0,1,0,0.1, COLOUR='RED', SURF_ID='FIRE' /\n\
+             &DUMP NFRAMES=10, DT_DEVCC=1 /\n\
+             &RADI NUMBER_RADIATION_ANGLES=104 /\n\
+             &SRUF ID='typo' /\n\
+             &ZZZZZZ X=1 /\n\
+             &TAIL /\n",
+        );
+        let unknown: Vec<String> = fds_file
+            .validate_names()
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        assert_eq!(
+            unknown,
+            vec![
+                "SURF 'FIRE': HRRPAU is not a parameter of SURF, did you mean HRRPUA?",
+                "OBST: COLOUR is not a parameter of OBST, did you mean COLOR?",
+                "DUMP: DT_DEVCC is not a parameter of DUMP, did you mean DT_DEVC?",
+                "&SRUF is not an FDS namelist group, did you mean SURF?",
+                "&ZZZZZZ is not an FDS namelist group",
+            ]
+        );
+        // The parameters of the misspelled namelists are not checked.
+        assert_eq!(fds_file.unknown_parameters.len(), 3);
+    }
+
+    #[test]
+    fn known_names() {
+        let fds_file = parse_and_decode_fds_input(
+            "&HEAD CHID='known' /\n\
+             &MESH IJK=10,10,10, XB=0,1,0,1,0,1 /\n\
+             &MISC SIMULATION_MODE='VLES', TMPA=25 /\n\
+             &SURF ID='FIRE', HRRPUA=500, TAU_Q=-300 /\n\
+             &OBST XB=0,1,0,1,0,0.1, SAWTOOTH=.FALSE., SURF_ID='FIRE' /\n\
+             &VENT DB='ZMAX', SURF_ID='OPEN' /\n\
+             &DEVC XB=0,1,0,1,0.5,0.5, QUANTITY='TEMPERATURE', ID='T', \
+                   SPATIAL_STATISTIC='MEAN', TEMPORAL_STATISTIC='RUNNING AVERAGE' /\n\
+             &EVAC ID='E', NUMBER_INITIAL_PERSONS=10, XB=0,1,0,1,0,0, AVATAR_COLOR='BLUE' /\n\
+             &EXIT ID='X', IOR=1, XYZ=1,0.5,0.5 /\n\
+             &PERS ID='P', DEFAULT_PROPERTIES='Adult' /\n\
+             &TAIL /\n",
+        );
+        assert_eq!(fds_file.validate_names(), vec![]);
+        assert!(fds_file.unknown_parameters.is_empty());
+    }
+
+    #[test]
+    fn rgb_errors() {
+        use fds_input_parser::decode::{FromParameterValueError, RGB};
+        use std::convert::TryFrom;
+        let nmls = parse_fds_input(
+            "&MESH RGB=255,0 /\n\
+             &MESH RGB=255,0.5,0 /\n\
+             &MESH RGB(1)=255, RGB(2)=0, RGB(4)=0 /\n\
+             &MESH RGB=255,128,0 /\n",
+        );
+        let rgb = |i: usize| RGB::try_from(nmls[i].parameters["RGB"].clone());
+        assert!(matches!(
+            rgb(0),
+            Err(FromParameterValueError::IncorrectArrayLength {
+                expected: 3,
+                found: 2
+            })
+        ));
+        assert!(matches!(
+            rgb(1),
+            Err(FromParameterValueError::InvalidElement { index: 2 })
+        ));
+        assert!(matches!(
+            rgb(2),
+            Err(FromParameterValueError::MissingElement { index: 3 })
+        ));
+        let rgb = rgb(3).unwrap();
+        assert_eq!((rgb.r, rgb.g, rgb.b), (255, 128, 0));
+    }
+
+    #[test]
+    fn example_names() {
+        // FDS runs these verification inputs, so none of their names may be
+        // reported as unknown.
+        for input in &[
+            include_str!("Examples/HVAC/HVAC_energy_pressure.fds"),
+            include_str!("Examples/Pyrolysis/surf_mass_part_char_cyl_gas.fds"),
+            include_str!("Examples/Visualization/thouse5.fds"),
+        ] {
+            assert_eq!(parse_and_decode_fds_input(input).validate_names(), vec![]);
+        }
+    }
+
+    #[test]
+    fn decode_errors() {
+        use decode::DecodeErrorKind;
+        let fds_file = parse_and_decode_fds_input(
+            "&HOLE ID='Door' /\n\
+             &RAMP T=0, F=0 /\n\
+             &RAMP ID='R', T=10 /\n\
+             &MULT ID='M', DXB=0,'a',0,0,0,0 /\n",
+        );
+        assert!(fds_file.holes.is_empty());
+        assert!(fds_file.ramps.is_empty());
+        assert!(fds_file.mults.is_empty());
+        let errors: Vec<_> = fds_file
+            .decode_errors
+            .iter()
+            .map(|e| (e.namelist.as_str(), e.kind.clone()))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                ("HOLE", DecodeErrorKind::MissingParameter("XB".to_string())),
+                ("RAMP", DecodeErrorKind::MissingParameter("ID".to_string())),
+                ("RAMP", DecodeErrorKind::MissingParameter("F".to_string())),
+                ("MULT", DecodeErrorKind::InvalidParameter("DXB".to_string())),
+            ]
+        );
+        assert_eq!(fds_file.unknown_namelists.len(), 4);
+    }
+
+    #[test]
+    fn decode_file_examples() {
+        let mut n_examples = 0;
+        for example_dir in std::fs::read_dir("tests/Examples").unwrap() {
+            let example_dir_path = example_dir.unwrap().path();
+            if !example_dir_path.is_dir() {
+                continue;
+            }
+            for example in std::fs::read_dir(&example_dir_path).unwrap() {
+                let example_path = example.unwrap().path();
+                if example_path.is_file() {
+                    let fds_file = parse_and_decode_fds_input_file(&example_path);
+                    assert_eq!(
+                        fds_file.decode_errors,
+                        vec![],
+                        "{:?} has namelists which could not be decoded",
+                        example_path
+                    );
+                    n_examples += 1;
+                }
+            }
+        }
+        assert!(n_examples > 500);
+    }
 
     #[test]
     fn float_error_test() {